@@ -0,0 +1,152 @@
+use syn::spanned::Spanned;
+use syn::{Attribute, Expr, Field, Ident, LitBool, LitInt, LitStr};
+
+#[derive(Default)]
+pub struct OptionAttr {
+    pub rename: Option<LitStr>,
+    pub description: Option<LitStr>,
+    pub min_value: Option<Expr>,
+    pub max_value: Option<Expr>,
+    pub min_length: Option<LitInt>,
+    pub max_length: Option<LitInt>,
+    pub autocomplete: Option<LitBool>,
+}
+
+impl OptionAttr {
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut ret = Self::default();
+        for attr in attrs {
+            if !attr.path().is_ident("option") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    ret.rename = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("description") {
+                    ret.description = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("min_value") {
+                    ret.min_value = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("max_value") {
+                    ret.max_value = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("min_length") {
+                    ret.min_length = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("max_length") {
+                    ret.max_length = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("autocomplete") {
+                    ret.autocomplete = Some(if meta.input.peek(syn::Token![=]) {
+                        meta.value()?.parse()?
+                    } else {
+                        LitBool::new(true, meta.path.span())
+                    });
+                } else {
+                    return Err(meta.error("unknown `option` attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(ret)
+    }
+}
+
+#[derive(Default)]
+pub struct CommandAttr {
+    pub name: Option<LitStr>,
+    pub rename: Option<LitStr>,
+    pub description: Option<LitStr>,
+}
+
+impl CommandAttr {
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut ret = Self::default();
+        for attr in attrs {
+            if !attr.path().is_ident("command") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    ret.name = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("rename") {
+                    ret.rename = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("description") {
+                    ret.description = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("unknown `command` attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(ret)
+    }
+}
+
+pub fn option_name(field: &Field, attr: &OptionAttr) -> String {
+    match &attr.rename {
+        Some(name) => name.value(),
+        None => ident_name(field.ident.as_ref().expect("named field")),
+    }
+}
+
+pub fn ident_name(ident: &Ident) -> String {
+    let name = ident.to_string();
+    name.strip_prefix("r#").map(str::to_owned).unwrap_or(name)
+}
+
+pub fn kebab_case(ident: &Ident) -> String {
+    let mut ret = String::new();
+    for (idx, ch) in ident_name(ident).char_indices() {
+        if ch.is_uppercase() {
+            if idx != 0 {
+                ret.push('-');
+            }
+            ret.extend(ch.to_lowercase());
+        } else if ch == '_' {
+            ret.push('-');
+        } else {
+            ret.push(ch);
+        }
+    }
+    ret
+}
+
+pub fn description(
+    attrs: &[Attribute],
+    explicit: Option<&LitStr>,
+    span: &impl quote::ToTokens,
+) -> syn::Result<String> {
+    if let Some(description) = explicit {
+        return Ok(description.value());
+    }
+
+    let mut lines = Vec::new();
+    for attr in attrs {
+        if !attr.path().is_ident("doc") {
+            continue;
+        }
+        if let syn::Meta::NameValue(syn::MetaNameValue {
+            value:
+                Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(doc),
+                    ..
+                }),
+            ..
+        }) = &attr.meta
+        {
+            let line = doc.value();
+            let line = line.trim();
+            if !line.is_empty() {
+                lines.push(line.to_owned());
+            }
+        }
+    }
+
+    if lines.is_empty() {
+        Err(syn::Error::new_spanned(
+            span,
+            "missing description; add a doc comment or a `description = \"...\"` attribute",
+        ))
+    } else {
+        Ok(lines.join(" "))
+    }
+}
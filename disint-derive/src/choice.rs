@@ -0,0 +1,199 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Fields, LitStr};
+
+use crate::attr::{ident_name, kebab_case};
+
+#[derive(Default)]
+struct ContainerAttr {
+    int: bool,
+}
+
+impl ContainerAttr {
+    fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut ret = Self::default();
+        for attr in attrs {
+            if !attr.path().is_ident("choice") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("int") {
+                    ret.int = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown `choice` attribute"))
+                }
+            })?;
+        }
+        Ok(ret)
+    }
+}
+
+#[derive(Default)]
+struct VariantAttr {
+    name: Option<LitStr>,
+    value: Option<LitStr>,
+    localizations: Vec<(LitStr, LitStr)>,
+}
+
+impl VariantAttr {
+    fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut ret = Self::default();
+        for attr in attrs {
+            if !attr.path().is_ident("choice") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    ret.name = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("value") {
+                    ret.value = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("localize") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let locale = content.parse()?;
+                    content.parse::<syn::Token![,]>()?;
+                    let name = content.parse()?;
+                    ret.localizations.push((locale, name));
+                } else {
+                    return Err(meta.error("unknown `choice` attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(ret)
+    }
+}
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`CommandChoice` can only be derived for fieldless enums",
+            ))
+        }
+    };
+
+    let container_attr = ContainerAttr::from_attrs(&input.attrs)?;
+
+    let mut choices = Vec::new();
+    let mut from_value = Vec::new();
+    let mut to_value = Vec::new();
+    for variant in variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "`CommandChoice` can only be derived for fieldless enums",
+            ));
+        }
+
+        let attr = VariantAttr::from_attrs(&variant.attrs)?;
+        let ident = &variant.ident;
+        let name = match &attr.name {
+            Some(name) => name.value(),
+            None => ident_name(ident),
+        };
+        let value = if container_attr.int {
+            if let Some(value) = &attr.value {
+                return Err(syn::Error::new_spanned(
+                    value,
+                    "integer choices take their value from the discriminant",
+                ));
+            }
+            from_value.push(quote! {
+                if value == Self::#ident as i64 {
+                    return ::std::option::Option::Some(Self::#ident);
+                }
+            });
+            quote! { Self::#ident as i64 }
+        } else {
+            let value = match &attr.value {
+                Some(value) => value.value(),
+                None => kebab_case(ident),
+            };
+            from_value.push(quote! {
+                if value == #value {
+                    return ::std::option::Option::Some(Self::#ident);
+                }
+            });
+            quote! { #value }
+        };
+        to_value.push(quote! {
+            Self::#ident => ::disint_model::OptionValue::from(#value),
+        });
+
+        let localizations = attr
+            .localizations
+            .iter()
+            .map(|(locale, name)| quote! { .localize(#locale, #name) });
+        choices.push(quote! {
+            ::disint_model::command::ApplicationCommandOptionChoice::new(#name, #value)
+                #(#localizations)*
+        });
+    }
+
+    let (option_type, extract_value) = if container_attr.int {
+        (
+            quote! { Integer },
+            quote! { let value = value.try_as_int()?; },
+        )
+    } else {
+        (
+            quote! { String },
+            quote! { let value = value.try_as_str()?; },
+        )
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::disint_model::command::CommandChoice for #ident #ty_generics #where_clause {
+            const OPTION_TYPE: ::disint_model::command::ApplicationCommandOptionType =
+                ::disint_model::command::ApplicationCommandOptionType::#option_type;
+
+            fn choices() -> ::std::vec::Vec<::disint_model::command::ApplicationCommandOptionChoice> {
+                ::std::vec![#(#choices),*]
+            }
+
+            fn from_value(value: &::disint_model::OptionValue) -> ::std::option::Option<Self> {
+                #extract_value
+                #(#from_value)*
+                ::std::option::Option::None
+            }
+
+            fn to_value(&self) -> ::disint_model::OptionValue {
+                match self {
+                    #(#to_value)*
+                }
+            }
+        }
+
+        impl #impl_generics ::disint_model::command::OptionKind for #ident #ty_generics #where_clause {
+            const OPTION_TYPE: ::disint_model::command::ApplicationCommandOptionType =
+                <Self as ::disint_model::command::CommandChoice>::OPTION_TYPE;
+
+            fn choices() -> ::std::vec::Vec<::disint_model::command::ApplicationCommandOptionChoice> {
+                <Self as ::disint_model::command::CommandChoice>::choices()
+            }
+        }
+
+        impl #impl_generics ::disint_model::interaction::options::FromOption for #ident #ty_generics #where_clause {
+            fn from_option(
+                data: &::disint_model::interaction::ApplicationCommandInteractionData,
+                name: &str,
+            ) -> ::std::result::Result<Self, ::disint_model::interaction::OptionError> {
+                let value = data.get(name)?;
+                <Self as ::disint_model::command::CommandChoice>::from_value(value).ok_or_else(|| {
+                    ::disint_model::interaction::OptionError::UnknownChoice {
+                        name: ::std::borrow::ToOwned::to_owned(name),
+                        value: ::std::string::ToString::to_string(value),
+                    }
+                })
+            }
+        }
+    })
+}
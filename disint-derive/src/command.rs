@@ -0,0 +1,214 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Field, Fields};
+
+use crate::attr::{description, kebab_case, option_name, CommandAttr, OptionAttr};
+
+struct Subcommand<'a> {
+    ident: &'a syn::Ident,
+    name: String,
+    description: String,
+    fields: Vec<SubcommandField<'a>>,
+    is_unit: bool,
+}
+
+struct SubcommandField<'a> {
+    field: &'a Field,
+    name: String,
+    description: String,
+    attr: OptionAttr,
+}
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`CommandDefinition` can only be derived for enums of subcommands",
+            ))
+        }
+    };
+
+    let command_attr = CommandAttr::from_attrs(&input.attrs)?;
+    let command_name = match &command_attr.name {
+        Some(name) => name.value(),
+        None => kebab_case(&input.ident),
+    };
+    let command_description = description(
+        &input.attrs,
+        command_attr.description.as_ref(),
+        &input.ident,
+    )?;
+
+    let subcommands = variants
+        .iter()
+        .map(|variant| {
+            let attr = CommandAttr::from_attrs(&variant.attrs)?;
+            if let Some(name) = &attr.name {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "use `rename` to set the name of a subcommand",
+                ));
+            }
+            let fields = match &variant.fields {
+                Fields::Named(fields) => fields
+                    .named
+                    .iter()
+                    .map(|field| {
+                        let attr = OptionAttr::from_attrs(&field.attrs)?;
+                        Ok(SubcommandField {
+                            field,
+                            name: option_name(field, &attr),
+                            description: description(
+                                &field.attrs,
+                                attr.description.as_ref(),
+                                field,
+                            )?,
+                            attr,
+                        })
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?,
+                Fields::Unit => Vec::new(),
+                Fields::Unnamed(_) => {
+                    return Err(syn::Error::new_spanned(
+                        variant,
+                        "subcommands must be unit variants or have named fields",
+                    ))
+                }
+            };
+            Ok(Subcommand {
+                ident: &variant.ident,
+                name: match &attr.rename {
+                    Some(name) => name.value(),
+                    None => kebab_case(&variant.ident),
+                },
+                description: description(&variant.attrs, attr.description.as_ref(), variant)?,
+                fields,
+                is_unit: matches!(variant.fields, Fields::Unit),
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let definition_subcommands = subcommands.iter().map(|subcommand| {
+        let name = &subcommand.name;
+        let description = &subcommand.description;
+        // Discord rejects optional options listed before required ones, so add
+        // required options first and keep field order otherwise.
+        let required = subcommand.fields.iter().map(|field| {
+            let ty = &field.field.ty;
+            let option = definition_option(field);
+            quote! {
+                let builder = if <#ty as ::disint_model::command::OptionKind>::REQUIRED {
+                    builder #option
+                } else {
+                    builder
+                };
+            }
+        });
+        let optional = subcommand.fields.iter().map(|field| {
+            let ty = &field.field.ty;
+            let option = definition_option(field);
+            quote! {
+                let builder = if <#ty as ::disint_model::command::OptionKind>::REQUIRED {
+                    builder
+                } else {
+                    builder #option
+                };
+            }
+        });
+        quote! {
+            .subcommand(#name, #description, |builder| {
+                let builder = builder.without_options();
+                #(#required)*
+                #(#optional)*
+                builder
+            })
+        }
+    });
+
+    let parse_arms = subcommands.iter().map(|subcommand| {
+        let ident = subcommand.ident;
+        let name = &subcommand.name;
+        let field_inits = subcommand.fields.iter().map(|field| {
+            let field_ident = &field.field.ident;
+            let ty = &field.field.ty;
+            let option_name = &field.name;
+            quote! {
+                #field_ident: <#ty as ::disint_model::interaction::options::FromOption>::from_option(data, #option_name)?
+            }
+        });
+        if subcommand.is_unit {
+            quote! {
+                [#name] => ::std::result::Result::Ok(Self::#ident),
+            }
+        } else {
+            quote! {
+                [#name] => ::std::result::Result::Ok(Self::#ident {
+                    #(#field_inits,)*
+                }),
+            }
+        }
+    });
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::disint_model::command::CommandDefinition for #ident #ty_generics #where_clause {
+            fn definition() -> ::disint_model::command::ApplicationCommand {
+                ::disint_model::command::ApplicationCommandBuilder::new(#command_name, #command_description)
+                    #(#definition_subcommands)*
+                    .finish()
+            }
+        }
+
+        impl #impl_generics ::disint_model::interaction::options::CommandOptions for #ident #ty_generics #where_clause {
+            fn from_data(
+                data: &::disint_model::interaction::ApplicationCommandInteractionData,
+            ) -> ::std::result::Result<Self, ::disint_model::interaction::OptionError> {
+                match data.subcommand_path().as_slice() {
+                    #(#parse_arms)*
+                    path => ::std::result::Result::Err(
+                        ::disint_model::interaction::OptionError::UnknownSubcommand {
+                            name: path.join(" "),
+                        },
+                    ),
+                }
+            }
+        }
+    })
+}
+
+fn definition_option(field: &SubcommandField<'_>) -> TokenStream {
+    let name = &field.name;
+    let description = &field.description;
+    let ty = &field.field.ty;
+    let attr = &field.attr;
+
+    let mut constraints = Vec::new();
+    if let Some(v) = &attr.min_value {
+        constraints.push(quote! { .min_value(#v) });
+    }
+    if let Some(v) = &attr.max_value {
+        constraints.push(quote! { .max_value(#v) });
+    }
+    if let Some(v) = &attr.min_length {
+        constraints.push(quote! { .min_length(#v) });
+    }
+    if let Some(v) = &attr.max_length {
+        constraints.push(quote! { .max_length(#v) });
+    }
+    if let Some(v) = &attr.autocomplete {
+        constraints.push(quote! { .autocomplete(#v) });
+    }
+
+    quote! {
+        .option(#name, #description, |builder| {
+            builder
+                .required(<#ty as ::disint_model::command::OptionKind>::REQUIRED)
+                .ty(<#ty as ::disint_model::command::OptionKind>::OPTION_TYPE)
+                .choices(<#ty as ::disint_model::command::OptionKind>::choices())
+                #(#constraints)*
+        })
+    }
+}
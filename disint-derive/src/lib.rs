@@ -0,0 +1,30 @@
+use proc_macro::TokenStream;
+
+mod attr;
+mod choice;
+mod command;
+mod options;
+
+#[proc_macro_derive(CommandOptions, attributes(option))]
+pub fn derive_command_options(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    options::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(CommandDefinition, attributes(command, option))]
+pub fn derive_command_definition(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    command::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(CommandChoice, attributes(choice))]
+pub fn derive_command_choice(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    choice::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,52 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields};
+
+use crate::attr::{option_name, OptionAttr};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "`CommandOptions` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`CommandOptions` can only be derived for structs",
+            ))
+        }
+    };
+
+    let field_inits = fields
+        .iter()
+        .map(|field| {
+            let attr = OptionAttr::from_attrs(&field.attrs)?;
+            let ident = &field.ident;
+            let ty = &field.ty;
+            let name = option_name(field, &attr);
+            Ok(quote! {
+                #ident: <#ty as ::disint_model::interaction::options::FromOption>::from_option(data, #name)?
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::disint_model::interaction::options::CommandOptions for #ident #ty_generics #where_clause {
+            fn from_data(
+                data: &::disint_model::interaction::ApplicationCommandInteractionData,
+            ) -> ::std::result::Result<Self, ::disint_model::interaction::OptionError> {
+                ::std::result::Result::Ok(Self {
+                    #(#field_inits,)*
+                })
+            }
+        }
+    })
+}
@@ -0,0 +1,127 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Color(u32);
+
+impl Color {
+    pub const BLACK: Color = Color(0x000000);
+    pub const WHITE: Color = Color(0xffffff);
+    pub const BLURPLE: Color = Color(0x5865f2);
+    pub const GREYPLE: Color = Color(0x99aab5);
+    pub const GREEN: Color = Color(0x57f287);
+    pub const YELLOW: Color = Color(0xfee75c);
+    pub const FUCHSIA: Color = Color(0xeb459e);
+    pub const RED: Color = Color(0xed4245);
+
+    /// Creates a color from a `0xRRGGBB` value, ignoring the upper byte.
+    pub const fn new(rgb: u32) -> Self {
+        Self(rgb & 0xffffff)
+    }
+
+    pub const fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self((red as u32) << 16 | (green as u32) << 8 | blue as u32)
+    }
+
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+
+    pub const fn red(self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    pub const fn green(self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    pub const fn blue(self) -> u8 {
+        self.0 as u8
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((red, green, blue): (u8, u8, u8)) -> Self {
+        Self::rgb(red, green, blue)
+    }
+}
+
+impl From<Color> for u32 {
+    fn from(color: Color) -> Self {
+        color.0
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseColorError {
+    input: String,
+}
+
+impl std::error::Error for ParseColorError {}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is not a hex color, expected `#RRGGBB` or `#RGB`",
+            self.input
+        )
+    }
+}
+
+impl std::str::FromStr for Color {
+    type Err = ParseColorError;
+
+    /// Parses `#RRGGBB`, `#RGB`, or either without the leading `#`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseColorError {
+            input: s.to_owned(),
+        };
+
+        let hex = s.strip_prefix('#').unwrap_or(s);
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(err());
+        }
+        let rgb = u32::from_str_radix(hex, 16).map_err(|_| err())?;
+        match hex.len() {
+            6 => Ok(Self(rgb)),
+            3 => {
+                let expand = |nibble: u32| (nibble & 0xf) * 0x11;
+                Ok(Self(
+                    expand(rgb >> 8) << 16 | expand(rgb >> 4) << 8 | expand(rgb),
+                ))
+            }
+            _ => Err(err()),
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:06x}", self.0)
+    }
+}
+
+impl serde::Serialize for Color {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        s.serialize_u32(self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let rgb = u32::deserialize(d)?;
+        if rgb > 0xffffff {
+            return Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(rgb as u64),
+                &"an RGB color",
+            ));
+        }
+        Ok(Self(rgb))
+    }
+}
@@ -4,6 +4,9 @@ mod state {
     #[derive(Debug)]
     pub struct NoOptions;
 
+    #[derive(Debug)]
+    pub struct ContextMenu;
+
     #[derive(Debug)]
     pub struct SubgroupOptions {
         pub(crate) options: Vec<super::ApplicationCommandOption>,
@@ -19,6 +22,11 @@ mod option_state {
     #[derive(Debug, Default)]
     pub struct SubcommandGroupIncomplete;
 
+    #[derive(Debug)]
+    pub struct SubcommandGroup {
+        pub(crate) options: Vec<super::ApplicationCommandOption>,
+    }
+
     #[derive(Debug, Default)]
     pub struct SubcommandIncomplete;
 
@@ -43,19 +51,49 @@ mod option_state {
         pub(crate) ty: super::ApplicationCommandOptionType,
         pub(crate) required: bool,
         pub(crate) choices: Vec<super::ApplicationCommandOptionChoice>,
+        pub(crate) min_value: Option<crate::OptionValue>,
+        pub(crate) max_value: Option<crate::OptionValue>,
+        pub(crate) min_length: Option<u16>,
+        pub(crate) max_length: Option<u16>,
+        pub(crate) autocomplete: Option<bool>,
     }
 }
 
 #[derive(Debug)]
 pub struct ApplicationCommandBuilder<State> {
+    ty: ApplicationCommandType,
     name: String,
     description: String,
     state: State,
 }
 
+impl ApplicationCommandBuilder<state::ContextMenu> {
+    pub fn user(name: impl Into<String>) -> Self {
+        Self::context_menu(ApplicationCommandType::User, name)
+    }
+
+    pub fn message(name: impl Into<String>) -> Self {
+        Self::context_menu(ApplicationCommandType::Message, name)
+    }
+
+    fn context_menu(ty: ApplicationCommandType, name: impl Into<String>) -> Self {
+        Self {
+            ty,
+            name: name.into(),
+            description: String::new(),
+            state: state::ContextMenu,
+        }
+    }
+
+    pub fn finish(self) -> ApplicationCommand {
+        finish_command(self.ty, self.name, self.description, Vec::new())
+    }
+}
+
 impl ApplicationCommandBuilder<state::NoOptions> {
     pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
         Self {
+            ty: ApplicationCommandType::ChatInput,
             name: name.into(),
             description: description.into(),
             state: state::NoOptions,
@@ -77,6 +115,7 @@ impl ApplicationCommandBuilder<state::NoOptions> {
         let option = builder.finish();
 
         ApplicationCommandBuilder {
+            ty: self.ty,
             name: self.name,
             description: self.description,
             state: state::SubgroupOptions {
@@ -100,6 +139,7 @@ impl ApplicationCommandBuilder<state::NoOptions> {
         let option = builder.finish();
 
         ApplicationCommandBuilder {
+            ty: self.ty,
             name: self.name,
             description: self.description,
             state: state::RegularOptions {
@@ -107,6 +147,96 @@ impl ApplicationCommandBuilder<state::NoOptions> {
             },
         }
     }
+
+    pub fn subcommand_group(
+        self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        f: impl FnOnce(
+            ApplicationCommandOptionBuilder<option_state::SubcommandGroupIncomplete>,
+        ) -> ApplicationCommandOptionBuilder<option_state::SubcommandGroup>,
+    ) -> ApplicationCommandBuilder<state::SubgroupOptions> {
+        ApplicationCommandBuilder {
+            ty: self.ty,
+            name: self.name,
+            description: self.description,
+            state: state::SubgroupOptions {
+                options: Vec::new(),
+            },
+        }
+        .subcommand_group(name, description, f)
+    }
+
+    pub fn finish(self) -> ApplicationCommand {
+        finish_command(self.ty, self.name, self.description, Vec::new())
+    }
+}
+
+impl ApplicationCommandBuilder<state::SubgroupOptions> {
+    pub fn subcommand(
+        mut self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        f: impl FnOnce(
+            ApplicationCommandOptionBuilder<option_state::SubcommandIncomplete>,
+        ) -> ApplicationCommandOptionBuilder<option_state::Subcommand>,
+    ) -> Self {
+        let builder = ApplicationCommandOptionBuilder::new(name, description);
+        self.state.options.push(f(builder).finish());
+        self
+    }
+
+    pub fn subcommand_group(
+        mut self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        f: impl FnOnce(
+            ApplicationCommandOptionBuilder<option_state::SubcommandGroupIncomplete>,
+        ) -> ApplicationCommandOptionBuilder<option_state::SubcommandGroup>,
+    ) -> Self {
+        let builder = ApplicationCommandOptionBuilder::new(name, description);
+        self.state.options.push(f(builder).finish());
+        self
+    }
+
+    pub fn finish(self) -> ApplicationCommand {
+        finish_command(self.ty, self.name, self.description, self.state.options)
+    }
+}
+
+impl ApplicationCommandBuilder<state::RegularOptions> {
+    pub fn option(
+        mut self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        f: impl FnOnce(
+            ApplicationCommandOptionBuilder<option_state::RegularIncomplete>,
+        ) -> ApplicationCommandOptionBuilder<option_state::Regular>,
+    ) -> Self {
+        let builder = ApplicationCommandOptionBuilder::new(name, description);
+        self.state.options.push(f(builder).finish());
+        self
+    }
+
+    pub fn finish(self) -> ApplicationCommand {
+        finish_command(self.ty, self.name, self.description, self.state.options)
+    }
+}
+
+fn finish_command(
+    ty: ApplicationCommandType,
+    name: String,
+    description: String,
+    options: Vec<ApplicationCommandOption>,
+) -> ApplicationCommand {
+    ApplicationCommand {
+        id: None,
+        application_id: None,
+        ty,
+        name,
+        description,
+        options,
+    }
 }
 
 #[derive(Debug)]
@@ -116,16 +246,136 @@ pub struct ApplicationCommandOptionBuilder<State> {
     state: State,
 }
 
+impl<State: Default> ApplicationCommandOptionBuilder<State> {
+    fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            state: Default::default(),
+        }
+    }
+}
+
+impl ApplicationCommandOptionBuilder<option_state::SubcommandGroupIncomplete> {
+    pub fn subcommand(
+        self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        f: impl FnOnce(
+            ApplicationCommandOptionBuilder<option_state::SubcommandIncomplete>,
+        ) -> ApplicationCommandOptionBuilder<option_state::Subcommand>,
+    ) -> ApplicationCommandOptionBuilder<option_state::SubcommandGroup> {
+        ApplicationCommandOptionBuilder {
+            name: self.name,
+            description: self.description,
+            state: option_state::SubcommandGroup {
+                options: Vec::new(),
+            },
+        }
+        .subcommand(name, description, f)
+    }
+}
+
+impl ApplicationCommandOptionBuilder<option_state::SubcommandGroup> {
+    pub fn subcommand(
+        mut self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        f: impl FnOnce(
+            ApplicationCommandOptionBuilder<option_state::SubcommandIncomplete>,
+        ) -> ApplicationCommandOptionBuilder<option_state::Subcommand>,
+    ) -> Self {
+        let builder = ApplicationCommandOptionBuilder::new(name, description);
+        self.state.options.push(f(builder).finish());
+        self
+    }
+
+    fn finish(self) -> ApplicationCommandOption {
+        ApplicationCommandOption {
+            options: self.state.options,
+            ..ApplicationCommandOption::new(
+                ApplicationCommandOptionType::SubCommandGroup,
+                self.name,
+                self.description,
+            )
+        }
+    }
+}
+
+impl ApplicationCommandOptionBuilder<option_state::SubcommandIncomplete> {
+    pub fn option(
+        self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        f: impl FnOnce(
+            ApplicationCommandOptionBuilder<option_state::RegularIncomplete>,
+        ) -> ApplicationCommandOptionBuilder<option_state::Regular>,
+    ) -> ApplicationCommandOptionBuilder<option_state::Subcommand> {
+        self.without_options().option(name, description, f)
+    }
+
+    pub fn without_options(self) -> ApplicationCommandOptionBuilder<option_state::Subcommand> {
+        ApplicationCommandOptionBuilder {
+            name: self.name,
+            description: self.description,
+            state: option_state::Subcommand {
+                options: Vec::new(),
+            },
+        }
+    }
+}
+
 impl ApplicationCommandOptionBuilder<option_state::Subcommand> {
+    pub fn option(
+        mut self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        f: impl FnOnce(
+            ApplicationCommandOptionBuilder<option_state::RegularIncomplete>,
+        ) -> ApplicationCommandOptionBuilder<option_state::Regular>,
+    ) -> Self {
+        let builder = ApplicationCommandOptionBuilder::new(name, description);
+        self.state.options.push(f(builder).finish());
+        self
+    }
+
     fn finish(self) -> ApplicationCommandOption {
         ApplicationCommandOption {
-            ty: ApplicationCommandOptionType::SubCommand,
+            options: self.state.options,
+            ..ApplicationCommandOption::new(
+                ApplicationCommandOptionType::SubCommand,
+                self.name,
+                self.description,
+            )
+        }
+    }
+}
+
+impl ApplicationCommandOptionBuilder<option_state::RegularIncomplete> {
+    pub fn required(self, required: bool) -> Self {
+        Self {
+            state: option_state::RegularIncomplete { required },
+            ..self
+        }
+    }
+
+    pub fn ty(
+        self,
+        ty: ApplicationCommandOptionType,
+    ) -> ApplicationCommandOptionBuilder<option_state::Regular> {
+        ApplicationCommandOptionBuilder {
             name: self.name,
             description: self.description,
-            required: None,
-            choices_options: Some(ChoicesOrOptions::Options {
-                options: self.state.options,
-            }),
+            state: option_state::Regular {
+                ty,
+                required: self.state.required,
+                choices: Vec::new(),
+                min_value: None,
+                max_value: None,
+                min_length: None,
+                max_length: None,
+                autocomplete: None,
+            },
         }
     }
 }
@@ -141,18 +391,66 @@ impl ApplicationCommandOptionBuilder<option_state::Regular> {
         }
     }
 
+    pub fn choice(mut self, name: impl Into<String>, value: impl Into<crate::OptionValue>) -> Self {
+        self.state
+            .choices
+            .push(ApplicationCommandOptionChoice::new(name, value));
+        self
+    }
+
+    pub fn choices(
+        mut self,
+        choices: impl IntoIterator<Item = ApplicationCommandOptionChoice>,
+    ) -> Self {
+        self.state.choices.extend(choices);
+        self
+    }
+
+    pub fn min_value(mut self, min_value: impl Into<crate::OptionValue>) -> Self {
+        self.state.min_value = Some(min_value.into());
+        self
+    }
+
+    pub fn max_value(mut self, max_value: impl Into<crate::OptionValue>) -> Self {
+        self.state.max_value = Some(max_value.into());
+        self
+    }
+
+    pub fn min_length(mut self, min_length: u16) -> Self {
+        self.state.min_length = Some(min_length);
+        self
+    }
+
+    pub fn max_length(mut self, max_length: u16) -> Self {
+        self.state.max_length = Some(max_length);
+        self
+    }
+
+    pub fn autocomplete(mut self, autocomplete: bool) -> Self {
+        self.state.autocomplete = Some(autocomplete);
+        self
+    }
+
     fn finish(self) -> ApplicationCommandOption {
-        let choices = if self.state.choices.is_empty() {
-            None
-        } else {
-            Some(ChoicesOrOptions::Choices { choices: self.state.choices })
-        };
+        let option_state::Regular {
+            ty,
+            required,
+            choices,
+            min_value,
+            max_value,
+            min_length,
+            max_length,
+            autocomplete,
+        } = self.state;
         ApplicationCommandOption {
-            ty: self.state.ty,
-            name: self.name,
-            description: self.description,
-            required: Some(self.state.required),
-            choices_options: choices,
+            required: Some(required),
+            choices,
+            min_value,
+            max_value,
+            min_length,
+            max_length,
+            autocomplete,
+            ..ApplicationCommandOption::new(ty, self.name, self.description)
         }
     }
 }
@@ -0,0 +1,71 @@
+use super::{ApplicationCommand, ApplicationCommandOptionChoice, ApplicationCommandOptionType};
+use crate::interaction::options::CommandOptions;
+use crate::interaction::resolved::{Attachment, PartialChannel, PartialGuildMember, Role};
+use crate::interaction::User;
+use crate::OptionValue;
+
+pub trait CommandDefinition: CommandOptions {
+    fn definition() -> ApplicationCommand;
+}
+
+pub trait OptionKind {
+    const OPTION_TYPE: ApplicationCommandOptionType;
+    const REQUIRED: bool = true;
+
+    fn choices() -> Vec<ApplicationCommandOptionChoice> {
+        Vec::new()
+    }
+}
+
+pub trait CommandChoice: Sized {
+    const OPTION_TYPE: ApplicationCommandOptionType;
+
+    fn choices() -> Vec<ApplicationCommandOptionChoice>;
+    fn from_value(value: &OptionValue) -> Option<Self>;
+    fn to_value(&self) -> OptionValue;
+}
+
+impl OptionKind for String {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::String;
+}
+
+impl OptionKind for i64 {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::Integer;
+}
+
+impl OptionKind for f64 {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::Number;
+}
+
+impl OptionKind for bool {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::Boolean;
+}
+
+impl OptionKind for User {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::User;
+}
+
+impl OptionKind for PartialGuildMember {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::User;
+}
+
+impl OptionKind for PartialChannel {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::Channel;
+}
+
+impl OptionKind for Role {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::Role;
+}
+
+impl OptionKind for Attachment {
+    const OPTION_TYPE: ApplicationCommandOptionType = ApplicationCommandOptionType::Attachment;
+}
+
+impl<T: OptionKind> OptionKind for Option<T> {
+    const OPTION_TYPE: ApplicationCommandOptionType = T::OPTION_TYPE;
+    const REQUIRED: bool = false;
+
+    fn choices() -> Vec<ApplicationCommandOptionChoice> {
+        T::choices()
+    }
+}
@@ -1,58 +1,173 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 pub mod builder;
+mod definition;
+mod validate;
 
+pub use crate::interaction::{ApplicationCommandOptionType, ApplicationCommandType};
 pub use builder::ApplicationCommandBuilder;
+pub use definition::{CommandChoice, CommandDefinition, OptionKind};
+pub use validate::Violation;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ApplicationCommand {
-    id: Option<String>,
-    application_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<crate::Snowflake>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    application_id: Option<crate::Snowflake>,
+    #[serde(rename = "type", default)]
+    ty: ApplicationCommandType,
     name: String,
     description: String,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     options: Vec<ApplicationCommandOption>,
 }
 
+impl ApplicationCommand {
+    pub fn id(&self) -> Option<crate::Snowflake> {
+        self.id
+    }
+
+    pub fn application_id(&self) -> Option<crate::Snowflake> {
+        self.application_id
+    }
+
+    pub fn command_type(&self) -> ApplicationCommandType {
+        self.ty
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn options(&self) -> &[ApplicationCommandOption] {
+        &self.options
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ApplicationCommandOption {
     #[serde(rename = "type")]
     ty: ApplicationCommandOptionType,
     name: String,
     description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     required: Option<bool>,
-    #[serde(flatten)]
-    choices_options: Option<ChoicesOrOptions>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    choices: Vec<ApplicationCommandOptionChoice>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    options: Vec<ApplicationCommandOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_value: Option<crate::OptionValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_value: Option<crate::OptionValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_length: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_length: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    autocomplete: Option<bool>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-#[repr(u32)]
-pub enum ApplicationCommandOptionType {
-    SubCommand = 1,
-    SubCommandGroup = 2,
-    String = 3,
-    Integer = 4,
-    Boolean = 5,
-    User = 6,
-    Channel = 7,
-    Role = 8,
-}
+impl ApplicationCommandOption {
+    fn new(ty: ApplicationCommandOptionType, name: String, description: String) -> Self {
+        Self {
+            ty,
+            name,
+            description,
+            required: None,
+            choices: Vec::new(),
+            options: Vec::new(),
+            min_value: None,
+            max_value: None,
+            min_length: None,
+            max_length: None,
+            autocomplete: None,
+        }
+    }
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
-enum ChoicesOrOptions {
-    Choices {
-        #[serde(skip_serializing_if = "Vec::is_empty")]
-        choices: Vec<ApplicationCommandOptionChoice>,
-    },
-    Options {
-        #[serde(skip_serializing_if = "Vec::is_empty")]
-        options: Vec<ApplicationCommandOption>,
-    },
+    pub fn option_type(&self) -> ApplicationCommandOptionType {
+        self.ty
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn is_required(&self) -> bool {
+        self.required.unwrap_or(false)
+    }
+
+    pub fn choices(&self) -> &[ApplicationCommandOptionChoice] {
+        &self.choices
+    }
+
+    pub fn options(&self) -> &[ApplicationCommandOption] {
+        &self.options
+    }
+
+    pub fn min_value(&self) -> Option<&crate::OptionValue> {
+        self.min_value.as_ref()
+    }
+
+    pub fn max_value(&self) -> Option<&crate::OptionValue> {
+        self.max_value.as_ref()
+    }
+
+    pub fn min_length(&self) -> Option<u16> {
+        self.min_length
+    }
+
+    pub fn max_length(&self) -> Option<u16> {
+        self.max_length
+    }
+
+    pub fn is_autocomplete(&self) -> bool {
+        self.autocomplete.unwrap_or(false)
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ApplicationCommandOptionChoice {
     name: String,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    name_localizations: HashMap<String, String>,
     value: crate::OptionValue,
 }
+
+impl ApplicationCommandOptionChoice {
+    pub fn new(name: impl Into<String>, value: impl Into<crate::OptionValue>) -> Self {
+        Self {
+            name: name.into(),
+            name_localizations: HashMap::new(),
+            value: value.into(),
+        }
+    }
+
+    pub fn localize(mut self, locale: impl Into<String>, name: impl Into<String>) -> Self {
+        self.name_localizations.insert(locale.into(), name.into());
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn name_localizations(&self) -> &HashMap<String, String> {
+        &self.name_localizations
+    }
+
+    pub fn value(&self) -> &crate::OptionValue {
+        &self.value
+    }
+}
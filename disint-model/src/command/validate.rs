@@ -0,0 +1,312 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use super::{
+    ApplicationCommand, ApplicationCommandOption, ApplicationCommandOptionType,
+    ApplicationCommandType,
+};
+use crate::interaction::{
+    ApplicationCommandInteractionData, ApplicationCommandInteractionDataOption,
+};
+use crate::{OptionValue, Snowflake};
+
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Violation {
+    CommandId {
+        expected: Snowflake,
+        found: Snowflake,
+    },
+    CommandName {
+        expected: String,
+        found: String,
+    },
+    CommandType {
+        expected: ApplicationCommandType,
+        found: ApplicationCommandType,
+    },
+    UnknownOption {
+        path: Vec<String>,
+    },
+    DuplicateOption {
+        path: Vec<String>,
+    },
+    MissingOption {
+        path: Vec<String>,
+    },
+    MissingSubcommand {
+        path: Vec<String>,
+    },
+    WrongType {
+        path: Vec<String>,
+        expected: ApplicationCommandOptionType,
+        found: ApplicationCommandOptionType,
+    },
+    InvalidChoice {
+        path: Vec<String>,
+        value: OptionValue,
+    },
+    OutOfRange {
+        path: Vec<String>,
+        value: OptionValue,
+        min: Option<OptionValue>,
+        max: Option<OptionValue>,
+    },
+    InvalidLength {
+        path: Vec<String>,
+        length: usize,
+        min: Option<u16>,
+        max: Option<u16>,
+    },
+}
+
+impl Violation {
+    pub fn path(&self) -> &[String] {
+        match self {
+            Violation::CommandId { .. }
+            | Violation::CommandName { .. }
+            | Violation::CommandType { .. } => &[],
+            Violation::UnknownOption { path }
+            | Violation::DuplicateOption { path }
+            | Violation::MissingOption { path }
+            | Violation::MissingSubcommand { path }
+            | Violation::WrongType { path, .. }
+            | Violation::InvalidChoice { path, .. }
+            | Violation::OutOfRange { path, .. }
+            | Violation::InvalidLength { path, .. } => path,
+        }
+    }
+}
+
+impl std::error::Error for Violation {}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = self.path().join(" ");
+        match self {
+            Violation::CommandId { expected, found } => {
+                write!(f, "command ID {} does not match {}", found, expected)
+            }
+            Violation::CommandName { expected, found } => {
+                write!(f, "command name `{}` does not match `{}`", found, expected)
+            }
+            Violation::CommandType { expected, found } => {
+                write!(f, "command type {:?} does not match {:?}", found, expected)
+            }
+            Violation::UnknownOption { .. } => write!(f, "unknown option `{}`", path),
+            Violation::DuplicateOption { .. } => write!(f, "duplicate option `{}`", path),
+            Violation::MissingOption { .. } => write!(f, "missing required option `{}`", path),
+            Violation::MissingSubcommand { .. } if path.is_empty() => {
+                f.write_str("missing subcommand")
+            }
+            Violation::MissingSubcommand { .. } => {
+                write!(f, "missing subcommand under `{}`", path)
+            }
+            Violation::WrongType {
+                expected, found, ..
+            } => write!(
+                f,
+                "option `{}` has type {:?}, expected {:?}",
+                path, found, expected
+            ),
+            Violation::InvalidChoice { value, .. } => {
+                write!(f, "option `{}` has invalid choice `{}`", path, value)
+            }
+            Violation::OutOfRange { value, .. } => {
+                write!(f, "option `{}` value {} is out of range", path, value)
+            }
+            Violation::InvalidLength { length, .. } => {
+                write!(f, "option `{}` has invalid length {}", path, length)
+            }
+        }
+    }
+}
+
+impl ApplicationCommand {
+    pub fn validate(&self, data: &ApplicationCommandInteractionData) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        if let Some(id) = self.id {
+            if id != data.id {
+                violations.push(Violation::CommandId {
+                    expected: id,
+                    found: data.id,
+                });
+            }
+        }
+        if self.name != data.name {
+            violations.push(Violation::CommandName {
+                expected: self.name.clone(),
+                found: data.name.clone(),
+            });
+        }
+        if self.ty != data.ty {
+            violations.push(Violation::CommandType {
+                expected: self.ty,
+                found: data.ty,
+            });
+        }
+
+        let mut validator = Validator {
+            path: Vec::new(),
+            autocomplete: data.focused_option().is_some(),
+            violations,
+        };
+        validator.options(&self.options, &data.options);
+        validator.violations
+    }
+}
+
+struct Validator {
+    path: Vec<String>,
+    autocomplete: bool,
+    violations: Vec<Violation>,
+}
+
+impl Validator {
+    fn path_with(&self, name: &str) -> Vec<String> {
+        let mut path = self.path.clone();
+        path.push(name.to_owned());
+        path
+    }
+
+    fn options(
+        &mut self,
+        definitions: &[ApplicationCommandOption],
+        options: &[ApplicationCommandInteractionDataOption],
+    ) {
+        let mut seen = HashSet::new();
+        for option in options {
+            let name = option.name();
+            if !seen.insert(name) {
+                self.violations.push(Violation::DuplicateOption {
+                    path: self.path_with(name),
+                });
+                continue;
+            }
+
+            let definition = match definitions.iter().find(|d| d.name == name) {
+                Some(definition) => definition,
+                None => {
+                    self.violations.push(Violation::UnknownOption {
+                        path: self.path_with(name),
+                    });
+                    continue;
+                }
+            };
+            if definition.ty != option.option_type() {
+                self.violations.push(Violation::WrongType {
+                    path: self.path_with(name),
+                    expected: definition.ty,
+                    found: option.option_type(),
+                });
+                continue;
+            }
+
+            match option {
+                ApplicationCommandInteractionDataOption::Value {
+                    value,
+                    focused: false,
+                    ..
+                } => self.value(definition, value),
+                ApplicationCommandInteractionDataOption::Value { .. } => {}
+                ApplicationCommandInteractionDataOption::Subcommand { options, .. }
+                | ApplicationCommandInteractionDataOption::SubcommandGroup { options, .. } => {
+                    self.path.push(name.to_owned());
+                    self.options(&definition.options, options);
+                    self.path.pop();
+                }
+            }
+        }
+
+        let has_subcommands = definitions.iter().any(|d| is_subcommand(d.ty));
+        if has_subcommands && !options.iter().any(|o| is_subcommand(o.option_type())) {
+            self.violations.push(Violation::MissingSubcommand {
+                path: self.path.clone(),
+            });
+        }
+        if !self.autocomplete {
+            for definition in definitions {
+                if definition.is_required() && !seen.contains(definition.name.as_str()) {
+                    self.violations.push(Violation::MissingOption {
+                        path: self.path_with(&definition.name),
+                    });
+                }
+            }
+        }
+    }
+
+    fn value(&mut self, definition: &ApplicationCommandOption, value: &OptionValue) {
+        if !definition.choices.is_empty()
+            && !definition
+                .choices
+                .iter()
+                .any(|choice| same_value(&choice.value, value))
+        {
+            self.violations.push(Violation::InvalidChoice {
+                path: self.path_with(&definition.name),
+                value: value.clone(),
+            });
+        }
+
+        if let Some(number) = as_number(value) {
+            let below = definition
+                .min_value
+                .as_ref()
+                .and_then(as_number)
+                .is_some_and(|min| number < min);
+            let above = definition
+                .max_value
+                .as_ref()
+                .and_then(as_number)
+                .is_some_and(|max| number > max);
+            if below || above {
+                self.violations.push(Violation::OutOfRange {
+                    path: self.path_with(&definition.name),
+                    value: value.clone(),
+                    min: definition.min_value.clone(),
+                    max: definition.max_value.clone(),
+                });
+            }
+        }
+
+        if let Some(s) = value.try_as_str() {
+            let length = s.chars().count();
+            let too_short = definition
+                .min_length
+                .is_some_and(|min| length < usize::from(min));
+            let too_long = definition
+                .max_length
+                .is_some_and(|max| length > usize::from(max));
+            if too_short || too_long {
+                self.violations.push(Violation::InvalidLength {
+                    path: self.path_with(&definition.name),
+                    length,
+                    min: definition.min_length,
+                    max: definition.max_length,
+                });
+            }
+        }
+    }
+}
+
+fn is_subcommand(ty: ApplicationCommandOptionType) -> bool {
+    matches!(
+        ty,
+        ApplicationCommandOptionType::SubCommand | ApplicationCommandOptionType::SubCommandGroup
+    )
+}
+
+fn as_number(value: &OptionValue) -> Option<f64> {
+    match value {
+        OptionValue::Int(v) => Some(*v as f64),
+        OptionValue::Number(v) => Some(*v),
+        _ => None,
+    }
+}
+
+fn same_value(a: &OptionValue, b: &OptionValue) -> bool {
+    match (as_number(a), as_number(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => a == b,
+    }
+}
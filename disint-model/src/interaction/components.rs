@@ -0,0 +1,781 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Snowflake;
+
+pub(crate) const MAX_ROWS: usize = 5;
+const MAX_BUTTONS_PER_ROW: usize = 5;
+const MAX_CUSTOM_ID_LENGTH: usize = 100;
+const MAX_SELECT_OPTIONS: usize = 25;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ComponentError {
+    TooManyRows { count: usize },
+    EmptyRow { row: usize },
+    TooManyButtons { row: usize, count: usize },
+    RowNotExclusive { row: usize },
+    InvalidButton { row: usize, style: ButtonStyle },
+    CustomIdTooLong { custom_id: String },
+    InvalidSelectOptions { custom_id: String, count: usize },
+    UnexpectedSelectOptions { custom_id: String },
+}
+
+impl std::error::Error for ComponentError {}
+
+impl fmt::Display for ComponentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComponentError::TooManyRows { count } => write!(
+                f,
+                "{} action rows given, at most {} allowed",
+                count, MAX_ROWS
+            ),
+            ComponentError::EmptyRow { row } => write!(f, "action row {} is empty", row),
+            ComponentError::TooManyButtons { row, count } => write!(
+                f,
+                "action row {} has {} buttons, at most {} allowed",
+                row, count, MAX_BUTTONS_PER_ROW
+            ),
+            ComponentError::RowNotExclusive { row } => write!(
+                f,
+                "action row {} mixes a select menu with other components",
+                row
+            ),
+            ComponentError::InvalidButton { row, style } => write!(
+                f,
+                "action row {} has a {:?} button with {}",
+                row,
+                style,
+                match style {
+                    ButtonStyle::Link => "a custom_id or sku_id, or without a url",
+                    ButtonStyle::Premium => "a custom_id or url, or without a sku_id",
+                    _ => "a url or sku_id, or without a custom_id",
+                }
+            ),
+            ComponentError::CustomIdTooLong { custom_id } => write!(
+                f,
+                "custom_id `{}` is longer than {} characters",
+                custom_id, MAX_CUSTOM_ID_LENGTH
+            ),
+            ComponentError::InvalidSelectOptions { custom_id, count } => write!(
+                f,
+                "select menu `{}` has {} options, expected 1 to {}",
+                custom_id, count, MAX_SELECT_OPTIONS
+            ),
+            ComponentError::UnexpectedSelectOptions { custom_id } => write!(
+                f,
+                "select menu `{}` is not a string select and cannot have options",
+                custom_id
+            ),
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, ComponentError>;
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(transparent)]
+pub struct Components(Vec<ActionRow>);
+
+impl Components {
+    pub fn rows(&self) -> &[ActionRow] {
+        &self.0
+    }
+
+    fn validate(rows: &[ActionRow]) -> Result<()> {
+        if rows.len() > MAX_ROWS {
+            return Err(ComponentError::TooManyRows { count: rows.len() });
+        }
+        for (idx, row) in rows.iter().enumerate() {
+            row.validate(idx)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'de> Deserialize<'de> for Components {
+    fn deserialize<D>(d: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let rows = Vec::<ActionRow>::deserialize(d)?;
+        Self::validate(&rows).map_err(serde::de::Error::custom)?;
+        Ok(Self(rows))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ComponentsBuilder {
+    rows: Vec<ActionRow>,
+}
+
+impl ComponentsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn row(mut self, row: ActionRow) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    pub fn finish(self) -> Result<Components> {
+        Components::validate(&self.rows)?;
+        Ok(Components(self.rows))
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+enum RowComponent {
+    Button(Button),
+    SelectMenu(SelectMenu),
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ActionRow {
+    components: Vec<RowComponent>,
+}
+
+impl Serialize for ActionRow {
+    fn serialize<S>(&self, s: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct ActionRowInner<'a> {
+            #[serde(rename = "type")]
+            ty: i32,
+            components: &'a [RowComponent],
+        }
+
+        ActionRowInner {
+            ty: 1,
+            components: &self.components,
+        }
+        .serialize(s)
+    }
+}
+
+impl<'de> Deserialize<'de> for RowComponent {
+    fn deserialize<D>(d: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let raw = serde_json::Value::deserialize(d)?;
+        let ty = match raw.get("type") {
+            Some(ty) => ty
+                .as_u64()
+                .ok_or_else(|| D::Error::custom("invalid component type"))?,
+            None => return Err(D::Error::missing_field("type")),
+        };
+
+        let component = match ty {
+            2 => serde_json::from_value(raw).map(RowComponent::Button),
+            3 | 5 | 6 | 7 | 8 => serde_json::from_value(raw).map(RowComponent::SelectMenu),
+            ty => {
+                return Err(D::Error::invalid_value(
+                    serde::de::Unexpected::Unsigned(ty),
+                    &"a component type allowed in an action row",
+                ))
+            }
+        };
+        component.map_err(D::Error::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for ActionRow {
+    fn deserialize<D>(d: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct ActionRowInner {
+            #[serde(rename = "type")]
+            ty: u8,
+            components: Vec<RowComponent>,
+        }
+
+        let ActionRowInner { ty, components } = ActionRowInner::deserialize(d)?;
+        if ty != 1 {
+            return Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(ty as u64),
+                &"an action row",
+            ));
+        }
+        Ok(Self { components })
+    }
+}
+
+impl ActionRow {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn button(mut self, button: Button) -> Self {
+        self.components.push(RowComponent::Button(button));
+        self
+    }
+
+    pub fn select_menu(mut self, select_menu: SelectMenu) -> Self {
+        self.components.push(RowComponent::SelectMenu(select_menu));
+        self
+    }
+
+    fn validate(&self, row: usize) -> Result<()> {
+        let count = self.components.len();
+        if count == 0 {
+            return Err(ComponentError::EmptyRow { row });
+        }
+
+        let buttons = self
+            .components
+            .iter()
+            .filter(|c| matches!(c, RowComponent::Button(_)))
+            .count();
+        if buttons != count && count > 1 {
+            return Err(ComponentError::RowNotExclusive { row });
+        }
+        if buttons > MAX_BUTTONS_PER_ROW {
+            return Err(ComponentError::TooManyButtons {
+                row,
+                count: buttons,
+            });
+        }
+
+        for component in &self.components {
+            let custom_id = match component {
+                RowComponent::Button(button) => {
+                    if !button.is_valid() {
+                        return Err(ComponentError::InvalidButton {
+                            row,
+                            style: button.style,
+                        });
+                    }
+                    button.custom_id.as_deref()
+                }
+                RowComponent::SelectMenu(select_menu) => {
+                    select_menu.validate()?;
+                    Some(&*select_menu.custom_id)
+                }
+            };
+            if let Some(custom_id) = custom_id {
+                check_custom_id(custom_id)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn check_custom_id(custom_id: &str) -> Result<()> {
+    if custom_id.chars().count() > MAX_CUSTOM_ID_LENGTH {
+        Err(ComponentError::CustomIdTooLong {
+            custom_id: custom_id.to_owned(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ButtonStyle {
+    Primary,
+    Secondary,
+    Success,
+    Danger,
+    Link,
+    Premium,
+}
+
+impl ButtonStyle {
+    pub fn from_u8(style: u8) -> Option<Self> {
+        use ButtonStyle::*;
+
+        Some(match style {
+            1 => Primary,
+            2 => Secondary,
+            3 => Success,
+            4 => Danger,
+            5 => Link,
+            6 => Premium,
+            _ => return None,
+        })
+    }
+
+    pub fn as_u8(self) -> u8 {
+        match self {
+            ButtonStyle::Primary => 1,
+            ButtonStyle::Secondary => 2,
+            ButtonStyle::Success => 3,
+            ButtonStyle::Danger => 4,
+            ButtonStyle::Link => 5,
+            ButtonStyle::Premium => 6,
+        }
+    }
+}
+
+impl Serialize for ButtonStyle {
+    fn serialize<S>(&self, s: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        s.serialize_u8(self.as_u8())
+    }
+}
+
+impl<'de> Deserialize<'de> for ButtonStyle {
+    fn deserialize<D>(d: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let val = u8::deserialize(d)?;
+        Self::from_u8(val).ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(val as u64),
+                &"a valid button style",
+            )
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Emoji {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<Snowflake>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    animated: bool,
+}
+
+impl Emoji {
+    pub fn unicode(name: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            name: Some(name.into()),
+            animated: false,
+        }
+    }
+
+    pub fn custom(id: Snowflake, name: impl Into<String>, animated: bool) -> Self {
+        Self {
+            id: Some(id),
+            name: Some(name.into()),
+            animated,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Button {
+    #[serde(rename = "type")]
+    ty: i32,
+    style: ButtonStyle,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji: Option<Emoji>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sku_id: Option<Snowflake>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    disabled: bool,
+}
+
+impl Button {
+    fn with_style(style: ButtonStyle) -> Self {
+        Self {
+            ty: 2,
+            style,
+            label: None,
+            emoji: None,
+            custom_id: None,
+            url: None,
+            sku_id: None,
+            disabled: false,
+        }
+    }
+
+    pub fn new(style: ButtonStyle, custom_id: impl Into<String>) -> Self {
+        Self {
+            custom_id: Some(custom_id.into()),
+            ..Self::with_style(style)
+        }
+    }
+
+    pub fn primary(custom_id: impl Into<String>) -> Self {
+        Self::new(ButtonStyle::Primary, custom_id)
+    }
+
+    pub fn secondary(custom_id: impl Into<String>) -> Self {
+        Self::new(ButtonStyle::Secondary, custom_id)
+    }
+
+    pub fn success(custom_id: impl Into<String>) -> Self {
+        Self::new(ButtonStyle::Success, custom_id)
+    }
+
+    pub fn danger(custom_id: impl Into<String>) -> Self {
+        Self::new(ButtonStyle::Danger, custom_id)
+    }
+
+    pub fn link(url: impl Into<String>) -> Self {
+        Self {
+            url: Some(url.into()),
+            ..Self::with_style(ButtonStyle::Link)
+        }
+    }
+
+    pub fn premium(sku_id: Snowflake) -> Self {
+        Self {
+            sku_id: Some(sku_id),
+            ..Self::with_style(ButtonStyle::Premium)
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    fn is_valid(&self) -> bool {
+        let custom_id = self.custom_id.is_some();
+        let url = self.url.is_some();
+        let sku_id = self.sku_id.is_some();
+        match self.style {
+            ButtonStyle::Link => url && !custom_id && !sku_id,
+            ButtonStyle::Premium => sku_id && !custom_id && !url,
+            _ => custom_id && !url && !sku_id,
+        }
+    }
+
+    pub fn emoji(mut self, emoji: Emoji) -> Self {
+        self.emoji = Some(emoji);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SelectMenuType {
+    String,
+    User,
+    Role,
+    Mentionable,
+    Channel,
+}
+
+impl SelectMenuType {
+    pub fn from_u8(ty: u8) -> Option<Self> {
+        use SelectMenuType::*;
+
+        Some(match ty {
+            3 => String,
+            5 => User,
+            6 => Role,
+            7 => Mentionable,
+            8 => Channel,
+            _ => return None,
+        })
+    }
+
+    pub fn as_u8(self) -> u8 {
+        match self {
+            SelectMenuType::String => 3,
+            SelectMenuType::User => 5,
+            SelectMenuType::Role => 6,
+            SelectMenuType::Mentionable => 7,
+            SelectMenuType::Channel => 8,
+        }
+    }
+}
+
+impl Serialize for SelectMenuType {
+    fn serialize<S>(&self, s: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        s.serialize_u8(self.as_u8())
+    }
+}
+
+impl<'de> Deserialize<'de> for SelectMenuType {
+    fn deserialize<D>(d: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let val = u8::deserialize(d)?;
+        Self::from_u8(val).ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(val as u64),
+                &"a valid select menu type",
+            )
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SelectMenu {
+    #[serde(rename = "type")]
+    ty: SelectMenuType,
+    custom_id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    options: Vec<SelectOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    placeholder: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_values: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_values: Option<u8>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    disabled: bool,
+}
+
+impl SelectMenu {
+    pub fn new(ty: SelectMenuType, custom_id: impl Into<String>) -> Self {
+        Self {
+            ty,
+            custom_id: custom_id.into(),
+            options: Vec::new(),
+            placeholder: None,
+            min_values: None,
+            max_values: None,
+            disabled: false,
+        }
+    }
+
+    pub fn string(custom_id: impl Into<String>) -> Self {
+        Self::new(SelectMenuType::String, custom_id)
+    }
+
+    pub fn user(custom_id: impl Into<String>) -> Self {
+        Self::new(SelectMenuType::User, custom_id)
+    }
+
+    pub fn role(custom_id: impl Into<String>) -> Self {
+        Self::new(SelectMenuType::Role, custom_id)
+    }
+
+    pub fn mentionable(custom_id: impl Into<String>) -> Self {
+        Self::new(SelectMenuType::Mentionable, custom_id)
+    }
+
+    pub fn channel(custom_id: impl Into<String>) -> Self {
+        Self::new(SelectMenuType::Channel, custom_id)
+    }
+
+    pub fn option(mut self, option: SelectOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    pub fn min_values(mut self, min_values: u8) -> Self {
+        self.min_values = Some(min_values);
+        self
+    }
+
+    pub fn max_values(mut self, max_values: u8) -> Self {
+        self.max_values = Some(max_values);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        let count = self.options.len();
+        match self.ty {
+            SelectMenuType::String if count == 0 || count > MAX_SELECT_OPTIONS => {
+                Err(ComponentError::InvalidSelectOptions {
+                    custom_id: self.custom_id.clone(),
+                    count,
+                })
+            }
+            SelectMenuType::String => Ok(()),
+            _ if count != 0 => Err(ComponentError::UnexpectedSelectOptions {
+                custom_id: self.custom_id.clone(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SelectOption {
+    label: String,
+    value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji: Option<Emoji>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    default: bool,
+}
+
+impl SelectOption {
+    pub fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+            description: None,
+            emoji: None,
+            default: false,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn emoji(mut self, emoji: Emoji) -> Self {
+        self.emoji = Some(emoji);
+        self
+    }
+
+    pub fn default(mut self, default: bool) -> Self {
+        self.default = default;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TextInputStyle {
+    Short,
+    Paragraph,
+}
+
+impl TextInputStyle {
+    pub fn from_u8(style: u8) -> Option<Self> {
+        match style {
+            1 => Some(TextInputStyle::Short),
+            2 => Some(TextInputStyle::Paragraph),
+            _ => None,
+        }
+    }
+
+    pub fn as_u8(self) -> u8 {
+        match self {
+            TextInputStyle::Short => 1,
+            TextInputStyle::Paragraph => 2,
+        }
+    }
+}
+
+impl Serialize for TextInputStyle {
+    fn serialize<S>(&self, s: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        s.serialize_u8(self.as_u8())
+    }
+}
+
+impl<'de> Deserialize<'de> for TextInputStyle {
+    fn deserialize<D>(d: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let val = u8::deserialize(d)?;
+        Self::from_u8(val).ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(val as u64),
+                &"a valid text input style",
+            )
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TextInput {
+    #[serde(rename = "type")]
+    ty: i32,
+    custom_id: String,
+    style: TextInputStyle,
+    label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_length: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_length: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    placeholder: Option<String>,
+}
+
+impl TextInput {
+    pub fn new(
+        custom_id: impl Into<String>,
+        style: TextInputStyle,
+        label: impl Into<String>,
+    ) -> Self {
+        Self {
+            ty: 4,
+            custom_id: custom_id.into(),
+            style,
+            label: label.into(),
+            min_length: None,
+            max_length: None,
+            required: None,
+            value: None,
+            placeholder: None,
+        }
+    }
+
+    pub fn short(custom_id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::new(custom_id, TextInputStyle::Short, label)
+    }
+
+    pub fn paragraph(custom_id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::new(custom_id, TextInputStyle::Paragraph, label)
+    }
+
+    pub fn custom_id(&self) -> &str {
+        &self.custom_id
+    }
+
+    pub fn min_length(mut self, min_length: u16) -> Self {
+        self.min_length = Some(min_length);
+        self
+    }
+
+    pub fn max_length(mut self, max_length: u16) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+        self
+    }
+
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+}
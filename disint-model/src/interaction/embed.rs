@@ -1,53 +1,448 @@
-use serde::Serialize;
+use std::fmt;
 
-#[derive(Clone, Debug, Default, Serialize)]
+use serde::{Deserialize, Serialize};
+
+use super::response::File;
+use crate::Color;
+
+const MAX_TITLE_LENGTH: usize = 256;
+const MAX_DESCRIPTION_LENGTH: usize = 4096;
+const MAX_FIELDS: usize = 25;
+const MAX_FIELD_NAME_LENGTH: usize = 256;
+const MAX_FIELD_VALUE_LENGTH: usize = 1024;
+const MAX_FOOTER_TEXT_LENGTH: usize = 2048;
+const MAX_AUTHOR_NAME_LENGTH: usize = 256;
+const MAX_TOTAL_LENGTH: usize = 6000;
+const MAX_EMBEDS: usize = 10;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum EmbedError {
+    TooLong {
+        part: &'static str,
+        length: usize,
+        max: usize,
+    },
+    FieldTooLong {
+        index: usize,
+        part: &'static str,
+        length: usize,
+        max: usize,
+    },
+    TooManyFields {
+        count: usize,
+    },
+    TotalTooLong {
+        length: usize,
+    },
+    TooManyEmbeds {
+        count: usize,
+    },
+}
+
+impl std::error::Error for EmbedError {}
+
+impl fmt::Display for EmbedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmbedError::TooLong { part, length, max } => write!(
+                f,
+                "embed {} is {} characters long, at most {} allowed",
+                part, length, max
+            ),
+            EmbedError::FieldTooLong {
+                index,
+                part,
+                length,
+                max,
+            } => write!(
+                f,
+                "embed field {} {} is {} characters long, at most {} allowed",
+                index, part, length, max
+            ),
+            EmbedError::TooManyFields { count } => write!(
+                f,
+                "{} embed fields given, at most {} allowed",
+                count, MAX_FIELDS
+            ),
+            EmbedError::TotalTooLong { length } => write!(
+                f,
+                "embeds contain {} characters in total, at most {} allowed",
+                length, MAX_TOTAL_LENGTH
+            ),
+            EmbedError::TooManyEmbeds { count } => {
+                write!(f, "{} embeds given, at most {} allowed", count, MAX_EMBEDS)
+            }
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, EmbedError>;
+
+/// Checks every embed of a message, including the character budget shared
+/// by all of them.
+pub fn validate_embeds(embeds: &[Embed]) -> Result<()> {
+    if embeds.len() > MAX_EMBEDS {
+        return Err(EmbedError::TooManyEmbeds {
+            count: embeds.len(),
+        });
+    }
+    let mut total = 0;
+    for embed in embeds {
+        embed.validate()?;
+        total += embed.total_length();
+    }
+    if total > MAX_TOTAL_LENGTH {
+        return Err(EmbedError::TotalTooLong { length: total });
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Embed {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub ty: Option<EmbedType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::timestamp::serialize_option"
+    )]
     pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub footer: Option<Footer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail: Option<Media>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image: Option<Media>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub video: Option<Media>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub provider: Option<Provider>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author: Option<Author>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fields: Option<Vec<Field>>,
 }
 
-#[derive(Clone, Debug, Default, Serialize)]
+impl Embed {
+    pub fn builder() -> EmbedBuilder {
+        EmbedBuilder::default()
+    }
+
+    /// Number of characters counted against the per-message budget.
+    pub fn total_length(&self) -> usize {
+        let title = self.title.as_deref().map_or(0, char_count);
+        let description = self.description.as_deref().map_or(0, char_count);
+        let footer = self.footer.as_ref().map_or(0, |f| char_count(&f.text));
+        let author = self
+            .author
+            .as_ref()
+            .and_then(|a| a.name.as_deref())
+            .map_or(0, char_count);
+        let fields = self
+            .fields
+            .iter()
+            .flatten()
+            .map(|field| char_count(&field.name) + char_count(&field.value))
+            .sum::<usize>();
+        title + description + footer + author + fields
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        check_length("title", self.title.as_deref(), MAX_TITLE_LENGTH)?;
+        check_length(
+            "description",
+            self.description.as_deref(),
+            MAX_DESCRIPTION_LENGTH,
+        )?;
+        check_length(
+            "footer text",
+            self.footer.as_ref().map(|f| &*f.text),
+            MAX_FOOTER_TEXT_LENGTH,
+        )?;
+        check_length(
+            "author name",
+            self.author.as_ref().and_then(|a| a.name.as_deref()),
+            MAX_AUTHOR_NAME_LENGTH,
+        )?;
+
+        let fields = self.fields.as_deref().unwrap_or_default();
+        if fields.len() > MAX_FIELDS {
+            return Err(EmbedError::TooManyFields {
+                count: fields.len(),
+            });
+        }
+        for (index, field) in fields.iter().enumerate() {
+            let parts = [
+                ("name", &field.name, MAX_FIELD_NAME_LENGTH),
+                ("value", &field.value, MAX_FIELD_VALUE_LENGTH),
+            ];
+            for (part, text, max) in parts {
+                let length = char_count(text);
+                if length > max {
+                    return Err(EmbedError::FieldTooLong {
+                        index,
+                        part,
+                        length,
+                        max,
+                    });
+                }
+            }
+        }
+
+        let length = self.total_length();
+        if length > MAX_TOTAL_LENGTH {
+            return Err(EmbedError::TotalTooLong { length });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum EmbedType {
+    Rich,
+    Image,
+    Video,
+    Gifv,
+    Article,
+    Link,
+    PollResult,
+    Unknown(String),
+}
+
+impl EmbedType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Rich => "rich",
+            Self::Image => "image",
+            Self::Video => "video",
+            Self::Gifv => "gifv",
+            Self::Article => "article",
+            Self::Link => "link",
+            Self::PollResult => "poll_result",
+            Self::Unknown(ty) => ty,
+        }
+    }
+}
+
+impl From<String> for EmbedType {
+    fn from(ty: String) -> Self {
+        match &*ty {
+            "rich" => Self::Rich,
+            "image" => Self::Image,
+            "video" => Self::Video,
+            "gifv" => Self::Gifv,
+            "article" => Self::Article,
+            "link" => Self::Link,
+            "poll_result" => Self::PollResult,
+            _ => Self::Unknown(ty),
+        }
+    }
+}
+
+impl Serialize for EmbedType {
+    fn serialize<S>(&self, s: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        s.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for EmbedType {
+    fn deserialize<D>(d: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(d).map(Self::from)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct EmbedBuilder {
+    embed: Embed,
+    truncate: bool,
+}
+
+impl EmbedBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.embed.title = Some(title.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.embed.description = Some(description.into());
+        self
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.embed.url = Some(url.into());
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: chrono::DateTime<chrono::Utc>) -> Self {
+        self.embed.timestamp = Some(timestamp);
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.embed.color = Some(color);
+        self
+    }
+
+    pub fn footer(mut self, footer: Footer) -> Self {
+        self.embed.footer = Some(footer);
+        self
+    }
+
+    pub fn thumbnail(mut self, thumbnail: Media) -> Self {
+        self.embed.thumbnail = Some(thumbnail);
+        self
+    }
+
+    pub fn image(mut self, image: Media) -> Self {
+        self.embed.image = Some(image);
+        self
+    }
+
+    pub fn author(mut self, author: Author) -> Self {
+        self.embed.author = Some(author);
+        self
+    }
+
+    pub fn field(mut self, field: Field) -> Self {
+        self.embed.fields.get_or_insert_with(Vec::new).push(field);
+        self
+    }
+
+    pub fn fields(mut self, fields: impl IntoIterator<Item = Field>) -> Self {
+        self.embed
+            .fields
+            .get_or_insert_with(Vec::new)
+            .extend(fields);
+        self
+    }
+
+    /// Cuts overlong texts short with an ellipsis instead of failing.
+    ///
+    /// The field count and the total length are still checked.
+    pub fn truncate(mut self) -> Self {
+        self.truncate = true;
+        self
+    }
+
+    pub fn finish(self) -> Result<Embed> {
+        let mut embed = self.embed;
+        if self.truncate {
+            if let Some(title) = &mut embed.title {
+                truncate(title, MAX_TITLE_LENGTH);
+            }
+            if let Some(description) = &mut embed.description {
+                truncate(description, MAX_DESCRIPTION_LENGTH);
+            }
+            if let Some(footer) = &mut embed.footer {
+                truncate(&mut footer.text, MAX_FOOTER_TEXT_LENGTH);
+            }
+            if let Some(name) = embed.author.as_mut().and_then(|a| a.name.as_mut()) {
+                truncate(name, MAX_AUTHOR_NAME_LENGTH);
+            }
+            for field in embed.fields.iter_mut().flatten() {
+                truncate(&mut field.name, MAX_FIELD_NAME_LENGTH);
+                truncate(&mut field.value, MAX_FIELD_VALUE_LENGTH);
+            }
+        }
+        embed.validate()?;
+        Ok(embed)
+    }
+}
+
+fn char_count(text: &str) -> usize {
+    text.chars().count()
+}
+
+fn check_length(part: &'static str, text: Option<&str>, max: usize) -> Result<()> {
+    let length = text.map_or(0, char_count);
+    if length > max {
+        return Err(EmbedError::TooLong { part, length, max });
+    }
+    Ok(())
+}
+
+fn truncate(text: &mut String, max: usize) {
+    if char_count(text) > max {
+        let end = text.char_indices().nth(max - 1).map_or(0, |(idx, _)| idx);
+        text.truncate(end);
+        text.push('…');
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Footer {
     pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icon_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub proxy_icon_url: Option<String>,
 }
 
-#[derive(Clone, Debug, Default, Serialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Media {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub proxy_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub height: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub width: Option<u32>,
 }
 
-#[derive(Clone, Debug, Default, Serialize)]
+impl Media {
+    pub fn attachment(file: &File) -> Self {
+        Self {
+            url: Some(file.attachment_url()),
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Provider {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
 }
 
-#[derive(Clone, Debug, Default, Serialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Author {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icon_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub proxy_icon_url: Option<String>,
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Field {
     name: String,
     value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     inline: Option<bool>,
 }
 
@@ -67,4 +462,28 @@ impl Field {
             inline: Some(true),
         }
     }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn is_inline(&self) -> bool {
+        self.inline.unwrap_or(false)
+    }
+
+    pub fn set_name(&mut self, name: impl Into<String>) {
+        self.name = name.into();
+    }
+
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        self.value = value.into();
+    }
+
+    pub fn set_inline(&mut self, inline: bool) {
+        self.inline = Some(inline);
+    }
 }
@@ -1,17 +1,69 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
+use crate::{Permissions, Snowflake};
+
+pub mod components;
 pub mod embed;
+pub mod multipart;
+pub mod options;
+pub mod resolved;
 pub mod response;
 
+pub use options::OptionError;
+pub use resolved::Resolved;
 pub use response::InteractionResponseBuilder;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize)]
 pub struct Interaction {
     version: i32,
-    id: String,
+    id: Snowflake,
     token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    context: Option<InteractionContextType>,
+    #[serde(
+        default,
+        skip_serializing_if = "AuthorizingIntegrationOwners::is_empty"
+    )]
+    authorizing_integration_owners: AuthorizingIntegrationOwners,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    app_permissions: Option<Permissions>,
     #[serde(flatten)]
     data: InteractionTypeAndData,
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl<'de> Deserialize<'de> for Interaction {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct InteractionInner {
+            version: i32,
+            id: Snowflake,
+            token: String,
+            context: Option<InteractionContextType>,
+            #[serde(default)]
+            authorizing_integration_owners: AuthorizingIntegrationOwners,
+            app_permissions: Option<Permissions>,
+            #[serde(flatten)]
+            rest: serde_json::Map<String, serde_json::Value>,
+        }
+
+        let inner = InteractionInner::deserialize(d)?;
+        let (data, extra) = InteractionTypeAndData::from_raw(inner.rest)?;
+        Ok(Self {
+            version: inner.version,
+            id: inner.id,
+            token: inner.token,
+            context: inner.context,
+            authorizing_integration_owners: inner.authorizing_integration_owners,
+            app_permissions: inner.app_permissions,
+            data,
+            extra,
+        })
+    }
 }
 
 impl Interaction {
@@ -19,14 +71,34 @@ impl Interaction {
         self.version
     }
 
-    pub fn interaction_id(&self) -> u64 {
-        self.id.parse().expect("Invalid Interaction ID")
+    pub fn interaction_id(&self) -> Snowflake {
+        self.id
     }
 
     pub fn token(&self) -> &str {
         &self.token
     }
 
+    pub fn context(&self) -> Option<InteractionContextType> {
+        self.context
+    }
+
+    pub fn authorizing_integration_owners(&self) -> &AuthorizingIntegrationOwners {
+        &self.authorizing_integration_owners
+    }
+
+    pub fn app_permissions(&self) -> Option<Permissions> {
+        self.app_permissions
+    }
+
+    pub fn source(&self) -> Option<&InteractionSource> {
+        self.data.source()
+    }
+
+    pub fn invoking_user(&self) -> Option<&User> {
+        self.data.source().map(InteractionSource::user)
+    }
+
     pub fn data(&self) -> &InteractionTypeAndData {
         &self.data
     }
@@ -36,30 +108,414 @@ impl Interaction {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug)]
 #[non_exhaustive]
-#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
 pub enum InteractionTypeAndData {
-    #[serde(deserialize_with = "interaction_ping")]
     Ping,
-    #[serde(deserialize_with = "interaction_command")]
     ApplicationCommand {
-        guild_id: String,
-        channel_id: String,
-        member: GuildMember,
+        channel_id: Snowflake,
+        source: InteractionSource,
         data: ApplicationCommandInteractionData,
     },
+    MessageComponent {
+        channel_id: Snowflake,
+        source: InteractionSource,
+        message: Message,
+        data: MessageComponentInteractionData,
+    },
+    ApplicationCommandAutocomplete {
+        channel_id: Snowflake,
+        source: InteractionSource,
+        data: ApplicationCommandInteractionData,
+    },
+    ModalSubmit {
+        channel_id: Snowflake,
+        source: InteractionSource,
+        message: Option<Message>,
+        data: ModalSubmitInteractionData,
+    },
+    Unknown {
+        ty: i32,
+        raw: serde_json::Value,
+    },
+}
+
+impl InteractionTypeAndData {
+    /// Parses the type-specific fields of an interaction, returning the
+    /// fields that weren't used.
+    fn from_raw<E>(
+        mut raw: serde_json::Map<String, serde_json::Value>,
+    ) -> Result<(Self, serde_json::Map<String, serde_json::Value>), E>
+    where
+        E: serde::de::Error,
+    {
+        use std::convert::TryFrom;
+
+        type Extra = serde_json::Map<String, serde_json::Value>;
+
+        #[derive(Deserialize)]
+        struct Invocation<T> {
+            guild_id: Option<Snowflake>,
+            channel_id: Snowflake,
+            member: Option<GuildMember>,
+            user: Option<User>,
+            message: Option<Message>,
+            data: T,
+            #[serde(flatten)]
+            extra: Extra,
+        }
+
+        fn invocation<T, E>(
+            mut raw: Extra,
+        ) -> Result<(Snowflake, InteractionSource, Option<Message>, T, Extra), E>
+        where
+            T: serde::de::DeserializeOwned,
+            E: serde::de::Error,
+        {
+            // `type` is written back by `InteractionTypeAndData` itself.
+            raw.remove("type");
+            let invocation: Invocation<T> =
+                serde_json::from_value(serde_json::Value::Object(raw)).map_err(E::custom)?;
+            let source = InteractionSource::from_parts(
+                invocation.guild_id,
+                invocation.member,
+                invocation.user,
+            )?;
+            Ok((
+                invocation.channel_id,
+                source,
+                invocation.message,
+                invocation.data,
+                invocation.extra,
+            ))
+        }
+
+        let ty = match raw.get("type") {
+            Some(ty) => ty
+                .as_i64()
+                .and_then(|ty| i32::try_from(ty).ok())
+                .ok_or_else(|| E::custom("invalid interaction type"))?,
+            None => return Err(E::missing_field("type")),
+        };
+
+        let parsed = match ty {
+            1 => {
+                raw.remove("type");
+                (InteractionTypeAndData::Ping, raw)
+            }
+            2 => {
+                let (channel_id, source, _, data, extra) = invocation(raw)?;
+                let data = InteractionTypeAndData::ApplicationCommand {
+                    channel_id,
+                    source,
+                    data,
+                };
+                (data, extra)
+            }
+            3 => {
+                let (channel_id, source, message, data, extra) = invocation(raw)?;
+                let data = InteractionTypeAndData::MessageComponent {
+                    channel_id,
+                    source,
+                    message: message.ok_or_else(|| E::missing_field("message"))?,
+                    data,
+                };
+                (data, extra)
+            }
+            4 => {
+                let (channel_id, source, _, data, extra) = invocation(raw)?;
+                let data = InteractionTypeAndData::ApplicationCommandAutocomplete {
+                    channel_id,
+                    source,
+                    data,
+                };
+                (data, extra)
+            }
+            5 => {
+                let (channel_id, source, message, data, extra) = invocation(raw)?;
+                let data = InteractionTypeAndData::ModalSubmit {
+                    channel_id,
+                    source,
+                    message,
+                    data,
+                };
+                (data, extra)
+            }
+            ty => {
+                let raw = serde_json::Value::Object(raw);
+                (InteractionTypeAndData::Unknown { ty, raw }, Extra::new())
+            }
+        };
+        Ok(parsed)
+    }
+}
+
+impl<'de> Deserialize<'de> for InteractionTypeAndData {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = serde_json::Map::deserialize(d)?;
+        Self::from_raw(raw).map(|(data, _)| data)
+    }
+}
+
+impl Serialize for InteractionTypeAndData {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        #[derive(Serialize)]
+        struct Invocation<'a, T> {
+            #[serde(rename = "type")]
+            ty: i32,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            guild_id: Option<Snowflake>,
+            channel_id: Snowflake,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            member: Option<&'a GuildMember>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            user: Option<&'a User>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            message: Option<&'a Message>,
+            data: &'a T,
+        }
+
+        impl<'a, T> Invocation<'a, T> {
+            fn new(
+                ty: i32,
+                channel_id: Snowflake,
+                source: &'a InteractionSource,
+                message: Option<&'a Message>,
+                data: &'a T,
+            ) -> Self {
+                let (guild_id, member, user) = match source {
+                    InteractionSource::Guild { guild_id, member } => {
+                        (Some(*guild_id), Some(member), None)
+                    }
+                    InteractionSource::Private { user } => (None, None, Some(user)),
+                };
+                Self {
+                    ty,
+                    guild_id,
+                    channel_id,
+                    member,
+                    user,
+                    message,
+                    data,
+                }
+            }
+        }
+
+        match self {
+            InteractionTypeAndData::Ping => {
+                let mut map = s.serialize_map(Some(1))?;
+                map.serialize_entry("type", &1)?;
+                map.end()
+            }
+            InteractionTypeAndData::ApplicationCommand {
+                channel_id,
+                source,
+                data,
+            } => Invocation::new(2, *channel_id, source, None, data).serialize(s),
+            InteractionTypeAndData::MessageComponent {
+                channel_id,
+                source,
+                message,
+                data,
+            } => Invocation::new(3, *channel_id, source, Some(message), data).serialize(s),
+            InteractionTypeAndData::ApplicationCommandAutocomplete {
+                channel_id,
+                source,
+                data,
+            } => Invocation::new(4, *channel_id, source, None, data).serialize(s),
+            InteractionTypeAndData::ModalSubmit {
+                channel_id,
+                source,
+                message,
+                data,
+            } => Invocation::new(5, *channel_id, source, message.as_ref(), data).serialize(s),
+            InteractionTypeAndData::Unknown { raw, .. } => {
+                s.collect_map(raw.as_object().into_iter().flatten())
+            }
+        }
+    }
+}
+
+impl InteractionTypeAndData {
+    pub fn channel_id(&self) -> Option<Snowflake> {
+        use InteractionTypeAndData::*;
+
+        match self {
+            Ping | Unknown { .. } => None,
+            ApplicationCommand { channel_id, .. }
+            | MessageComponent { channel_id, .. }
+            | ApplicationCommandAutocomplete { channel_id, .. }
+            | ModalSubmit { channel_id, .. } => Some(*channel_id),
+        }
+    }
+
+    pub fn source(&self) -> Option<&InteractionSource> {
+        use InteractionTypeAndData::*;
+
+        match self {
+            Ping | Unknown { .. } => None,
+            ApplicationCommand { source, .. }
+            | MessageComponent { source, .. }
+            | ApplicationCommandAutocomplete { source, .. }
+            | ModalSubmit { source, .. } => Some(source),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InteractionSource {
+    Guild {
+        guild_id: Snowflake,
+        member: GuildMember,
+    },
+    Private {
+        user: User,
+    },
 }
 
-#[derive(Debug, Deserialize)]
+impl InteractionSource {
+    fn from_parts<E: serde::de::Error>(
+        guild_id: Option<Snowflake>,
+        member: Option<GuildMember>,
+        user: Option<User>,
+    ) -> Result<Self, E> {
+        match (guild_id, member, user) {
+            (Some(guild_id), Some(member), _) => Ok(Self::Guild { guild_id, member }),
+            (Some(_), None, _) => Err(E::missing_field("member")),
+            (None, _, Some(user)) => Ok(Self::Private { user }),
+            (None, _, None) => Err(E::missing_field("user")),
+        }
+    }
+
+    pub fn guild_id(&self) -> Option<Snowflake> {
+        match self {
+            Self::Guild { guild_id, .. } => Some(*guild_id),
+            Self::Private { .. } => None,
+        }
+    }
+
+    pub fn member(&self) -> Option<&GuildMember> {
+        match self {
+            Self::Guild { member, .. } => Some(member),
+            Self::Private { .. } => None,
+        }
+    }
+
+    pub fn user(&self) -> &User {
+        match self {
+            Self::Guild { member, .. } => member.user(),
+            Self::Private { user } => user,
+        }
+    }
+
+    pub fn is_guild(&self) -> bool {
+        matches!(self, Self::Guild { .. })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum InteractionContextType {
+    Guild,
+    BotDm,
+    PrivateChannel,
+}
+
+impl InteractionContextType {
+    pub fn from_u8(ty: u8) -> Option<Self> {
+        use InteractionContextType::*;
+
+        Some(match ty {
+            0 => Guild,
+            1 => BotDm,
+            2 => PrivateChannel,
+            _ => return None,
+        })
+    }
+
+    pub fn as_u8(self) -> u8 {
+        use InteractionContextType::*;
+
+        match self {
+            Guild => 0,
+            BotDm => 1,
+            PrivateChannel => 2,
+        }
+    }
+}
+
+impl Serialize for InteractionContextType {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        s.serialize_u8(self.as_u8())
+    }
+}
+
+impl<'de> Deserialize<'de> for InteractionContextType {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let ty = u8::deserialize(d)?;
+        Self::from_u8(ty).ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(ty as u64),
+                &"a valid interaction context type",
+            )
+        })
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AuthorizingIntegrationOwners {
+    #[serde(rename = "0", skip_serializing_if = "Option::is_none")]
+    guild_install: Option<Snowflake>,
+    #[serde(rename = "1", skip_serializing_if = "Option::is_none")]
+    user_install: Option<Snowflake>,
+}
+
+impl AuthorizingIntegrationOwners {
+    /// Returns the ID of the guild the app is installed to, or `Some(0)` if the
+    /// interaction was triggered from the app's bot DM.
+    pub fn guild_install(&self) -> Option<Snowflake> {
+        self.guild_install
+    }
+
+    pub fn user_install(&self) -> Option<Snowflake> {
+        self.user_install
+    }
+
+    fn is_empty(&self) -> bool {
+        self.guild_install.is_none() && self.user_install.is_none()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GuildMember {
     user: User,
     nick: Option<String>,
-    roles: Vec<String>,
+    roles: Vec<Snowflake>,
+    #[serde(serialize_with = "crate::timestamp::serialize")]
     joined_at: chrono::DateTime<chrono::Utc>,
+    #[serde(serialize_with = "crate::timestamp::serialize_option")]
     premium_since: Option<chrono::DateTime<chrono::Utc>>,
     deaf: bool,
     mute: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permissions: Option<Permissions>,
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
 }
 
 impl GuildMember {
@@ -75,12 +531,8 @@ impl GuildMember {
         self.nick.as_deref().unwrap_or(&self.user.username)
     }
 
-    pub fn roles(&self) -> Vec<u64> {
-        self.roles
-            .iter()
-            .map(|s| s.parse())
-            .collect::<Result<_, _>>()
-            .expect("Invalid Role ID")
+    pub fn roles(&self) -> &[Snowflake] {
+        &self.roles
     }
 
     pub fn joined_at(&self) -> chrono::DateTime<chrono::Utc> {
@@ -102,28 +554,44 @@ impl GuildMember {
     pub fn is_mute(&self) -> bool {
         self.mute
     }
+
+    pub fn permissions(&self) -> Option<Permissions> {
+        self.permissions
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct User {
-    id: String,
+    id: Snowflake,
     username: String,
+    #[serde(deserialize_with = "discriminator")]
     discriminator: String,
     avatar: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     bot: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     system: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     mfa_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     verified: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     flags: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     premium_type: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     public_flags: Option<i32>,
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
 }
 
 impl User {
-    pub fn id(&self) -> u64 {
-        self.id.parse().expect("Invalid User ID")
+    pub fn id(&self) -> Snowflake {
+        self.id
     }
 
     pub fn username(&self) -> &str {
@@ -151,8 +619,11 @@ impl User {
             };
             format!("/avatars/{}/{}.{}", self.id, avatar, ext)
         } else {
-            let discriminator = self.discriminator.parse::<u32>().unwrap();
-            format!("/embed/avatars/{}.png", discriminator % 5)
+            let index = match self.discriminator.parse::<u64>() {
+                Ok(discriminator) if discriminator != 0 => discriminator % 5,
+                _ => (self.id.get() >> 22) % 6,
+            };
+            format!("/embed/avatars/{}.png", index)
         }
     }
 
@@ -167,75 +638,663 @@ impl User {
     pub fn is_mfa_enabled(&self) -> bool {
         self.mfa_enabled.unwrap_or(false)
     }
+
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    pub fn is_verified(&self) -> Option<bool> {
+        self.verified
+    }
+
+    pub fn email(&self) -> Option<&str> {
+        self.email.as_deref()
+    }
+
+    pub fn flags(&self) -> Option<i32> {
+        self.flags
+    }
+
+    pub fn premium_type(&self) -> Option<i32> {
+        self.premium_type
+    }
+
+    pub fn public_flags(&self) -> Option<i32> {
+        self.public_flags
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Message {
+    id: Snowflake,
+    channel_id: Snowflake,
+    author: User,
+    content: String,
+    #[serde(serialize_with = "crate::timestamp::serialize")]
+    timestamp: chrono::DateTime<chrono::Utc>,
+    #[serde(serialize_with = "crate::timestamp::serialize_option")]
+    edited_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tts: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pinned: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flags: Option<crate::MessageFlags>,
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Message {
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    pub fn channel_id(&self) -> Snowflake {
+        self.channel_id
+    }
+
+    pub fn author(&self) -> &User {
+        &self.author
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn timestamp(&self) -> chrono::DateTime<chrono::Utc> {
+        self.timestamp
+    }
+
+    pub fn edited_timestamp(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.edited_timestamp
+    }
+
+    pub fn is_tts(&self) -> bool {
+        self.tts.unwrap_or(false)
+    }
+
+    pub fn is_pinned(&self) -> bool {
+        self.pinned.unwrap_or(false)
+    }
+
+    pub fn flags(&self) -> crate::MessageFlags {
+        self.flags.unwrap_or_default()
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ApplicationCommandInteractionData {
-    pub id: String,
+    pub id: Snowflake,
     pub name: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub options: Vec<ApplicationCommandInteractionDataOption>,
+    #[serde(default, skip_serializing_if = "Resolved::is_empty")]
+    pub resolved: Resolved,
+    #[serde(rename = "type", default)]
+    pub ty: ApplicationCommandType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<Snowflake>,
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(untagged)]
+impl ApplicationCommandInteractionData {
+    pub fn resolve_user(&self, value: &crate::OptionValue) -> Option<&User> {
+        self.resolved.user(value.try_as_user()?)
+    }
+
+    pub fn resolve_member(
+        &self,
+        value: &crate::OptionValue,
+    ) -> Option<&resolved::PartialGuildMember> {
+        self.resolved.member(value.try_as_user()?)
+    }
+
+    pub fn resolve_role(&self, value: &crate::OptionValue) -> Option<&resolved::Role> {
+        self.resolved.role(value.try_as_role()?)
+    }
+
+    pub fn resolve_channel(&self, value: &crate::OptionValue) -> Option<&resolved::PartialChannel> {
+        self.resolved.channel(value.try_as_channel()?)
+    }
+
+    pub fn resolve_attachment(&self, value: &crate::OptionValue) -> Option<&resolved::Attachment> {
+        self.resolved.attachment(value.try_as_attachment()?)
+    }
+
+    pub fn target(&self) -> Option<CommandTarget<'_>> {
+        let target_id = self.target_id?;
+        match self.ty {
+            ApplicationCommandType::User => {
+                let user = self.resolved.user(target_id)?;
+                let member = self.resolved.member(target_id);
+                Some(CommandTarget::User { user, member })
+            }
+            ApplicationCommandType::Message => {
+                self.resolved.message(target_id).map(CommandTarget::Message)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn target_user(&self) -> Option<&User> {
+        match self.target()? {
+            CommandTarget::User { user, .. } => Some(user),
+            CommandTarget::Message(_) => None,
+        }
+    }
+
+    pub fn target_message(&self) -> Option<&Message> {
+        match self.target()? {
+            CommandTarget::Message(message) => Some(message),
+            CommandTarget::User { .. } => None,
+        }
+    }
+
+    pub fn focused_option(&self) -> Option<FocusedOption<'_>> {
+        let mut path = Vec::new();
+        let mut options = &self.options;
+        loop {
+            let mut next = None;
+            for option in options {
+                match option {
+                    ApplicationCommandInteractionDataOption::Value {
+                        name,
+                        value,
+                        focused: true,
+                        ..
+                    } => {
+                        return Some(FocusedOption { path, name, value });
+                    }
+                    ApplicationCommandInteractionDataOption::Subcommand { name, options }
+                    | ApplicationCommandInteractionDataOption::SubcommandGroup { name, options } => {
+                        next = Some((name, options));
+                    }
+                    _ => {}
+                }
+            }
+
+            let (name, subcommand_options) = next?;
+            path.push(name.as_str());
+            options = subcommand_options;
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ApplicationCommandType {
+    #[default]
+    ChatInput,
+    User,
+    Message,
+    PrimaryEntryPoint,
+    /// A command type this crate doesn't know about yet.
+    Unknown(u8),
+}
+
+impl ApplicationCommandType {
+    pub fn from_u8(ty: u8) -> Option<Self> {
+        use ApplicationCommandType::*;
+
+        Some(match ty {
+            1 => ChatInput,
+            2 => User,
+            3 => Message,
+            4 => PrimaryEntryPoint,
+            _ => return None,
+        })
+    }
+
+    pub fn as_u8(self) -> u8 {
+        use ApplicationCommandType::*;
+
+        match self {
+            ChatInput => 1,
+            User => 2,
+            Message => 3,
+            PrimaryEntryPoint => 4,
+            Unknown(ty) => ty,
+        }
+    }
+}
+
+impl Serialize for ApplicationCommandType {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        s.serialize_u8(self.as_u8())
+    }
+}
+
+impl<'de> Deserialize<'de> for ApplicationCommandType {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let ty = u8::deserialize(d)?;
+        Ok(Self::from_u8(ty).unwrap_or(Self::Unknown(ty)))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ApplicationCommandOptionType {
+    SubCommand,
+    SubCommandGroup,
+    String,
+    Integer,
+    Boolean,
+    User,
+    Channel,
+    Role,
+    Mentionable,
+    Number,
+    Attachment,
+}
+
+impl ApplicationCommandOptionType {
+    pub fn from_u8(ty: u8) -> Option<Self> {
+        use ApplicationCommandOptionType::*;
+
+        Some(match ty {
+            1 => SubCommand,
+            2 => SubCommandGroup,
+            3 => String,
+            4 => Integer,
+            5 => Boolean,
+            6 => User,
+            7 => Channel,
+            8 => Role,
+            9 => Mentionable,
+            10 => Number,
+            11 => Attachment,
+            _ => return None,
+        })
+    }
+
+    pub fn as_u8(self) -> u8 {
+        use ApplicationCommandOptionType::*;
+
+        match self {
+            SubCommand => 1,
+            SubCommandGroup => 2,
+            String => 3,
+            Integer => 4,
+            Boolean => 5,
+            User => 6,
+            Channel => 7,
+            Role => 8,
+            Mentionable => 9,
+            Number => 10,
+            Attachment => 11,
+        }
+    }
+}
+
+impl Serialize for ApplicationCommandOptionType {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        s.serialize_u8(self.as_u8())
+    }
+}
+
+impl<'de> Deserialize<'de> for ApplicationCommandOptionType {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let ty = u8::deserialize(d)?;
+        Self::from_u8(ty).ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(ty as u64),
+                &"a valid application command option type",
+            )
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum CommandTarget<'a> {
+    User {
+        user: &'a User,
+        member: Option<&'a resolved::PartialGuildMember>,
+    },
+    Message(&'a Message),
+}
+
+#[derive(Debug)]
+pub struct FocusedOption<'a> {
+    path: Vec<&'a str>,
+    name: &'a str,
+    value: &'a crate::OptionValue,
+}
+
+impl<'a> FocusedOption<'a> {
+    pub fn path(&self) -> &[&'a str] {
+        &self.path
+    }
+
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    pub fn value(&self) -> &'a crate::OptionValue {
+        self.value
+    }
+
+    pub fn partial_input(&self) -> String {
+        self.value.to_string()
+    }
+}
+
+#[derive(Debug)]
 pub enum ApplicationCommandInteractionDataOption {
     Value {
         name: String,
+        ty: ApplicationCommandOptionType,
         value: crate::OptionValue,
+        focused: bool,
     },
     Subcommand {
         name: String,
         options: Vec<ApplicationCommandInteractionDataOption>,
     },
+    SubcommandGroup {
+        name: String,
+        options: Vec<ApplicationCommandInteractionDataOption>,
+    },
 }
 
-fn interaction_ping<'de, D>(d: D) -> Result<(), D::Error>
-where
-    D: serde::Deserializer<'de>,
-{
-    #[derive(Deserialize)]
-    struct Ping {
-        #[serde(rename = "type")]
-        ty: i32,
+impl Serialize for ApplicationCommandInteractionDataOption {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct RawOption<'a> {
+            name: &'a str,
+            #[serde(rename = "type")]
+            ty: ApplicationCommandOptionType,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            value: Option<&'a crate::OptionValue>,
+            #[serde(skip_serializing_if = "<[_]>::is_empty")]
+            options: &'a [ApplicationCommandInteractionDataOption],
+            #[serde(skip_serializing_if = "std::ops::Not::not")]
+            focused: bool,
+        }
+
+        let raw = match self {
+            ApplicationCommandInteractionDataOption::Value {
+                name,
+                ty,
+                value,
+                focused,
+            } => RawOption {
+                name,
+                ty: *ty,
+                value: Some(value),
+                options: &[],
+                focused: *focused,
+            },
+            ApplicationCommandInteractionDataOption::Subcommand { name, options } => RawOption {
+                name,
+                ty: ApplicationCommandOptionType::SubCommand,
+                value: None,
+                options,
+                focused: false,
+            },
+            ApplicationCommandInteractionDataOption::SubcommandGroup { name, options } => {
+                RawOption {
+                    name,
+                    ty: ApplicationCommandOptionType::SubCommandGroup,
+                    value: None,
+                    options,
+                    focused: false,
+                }
+            }
+        };
+        raw.serialize(s)
     }
+}
 
-    let ping = Ping::deserialize(d)?;
-    if ping.ty == 1 {
-        Ok(())
-    } else {
-        Err(serde::de::Error::custom("Not a Ping type"))
+impl<'de> Deserialize<'de> for ApplicationCommandInteractionDataOption {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct RawOption {
+            name: String,
+            #[serde(rename = "type")]
+            ty: ApplicationCommandOptionType,
+            value: Option<crate::OptionValue>,
+            #[serde(default)]
+            options: Vec<ApplicationCommandInteractionDataOption>,
+            #[serde(default)]
+            focused: bool,
+        }
+
+        let RawOption {
+            name,
+            ty,
+            value,
+            options,
+            focused,
+        } = RawOption::deserialize(d)?;
+        let option = match ty {
+            ApplicationCommandOptionType::SubCommand => {
+                ApplicationCommandInteractionDataOption::Subcommand { name, options }
+            }
+            ApplicationCommandOptionType::SubCommandGroup => {
+                ApplicationCommandInteractionDataOption::SubcommandGroup { name, options }
+            }
+            ty => {
+                let value = value.ok_or_else(|| serde::de::Error::missing_field("value"))?;
+                // Autocomplete sends whatever the user has typed so far, regardless of the option type.
+                let value = if focused {
+                    value
+                } else {
+                    crate::OptionValue::from_raw(ty, value)?
+                };
+                ApplicationCommandInteractionDataOption::Value {
+                    name,
+                    ty,
+                    value,
+                    focused,
+                }
+            }
+        };
+        Ok(option)
     }
 }
 
-fn interaction_command<'de, D>(
-    d: D,
-) -> Result<
-    (
-        String,
-        String,
-        GuildMember,
-        ApplicationCommandInteractionData,
-    ),
-    D::Error,
->
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MessageComponentInteractionData {
+    custom_id: String,
+    component_type: ComponentType,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    values: Vec<String>,
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl MessageComponentInteractionData {
+    pub fn custom_id(&self) -> &str {
+        &self.custom_id
+    }
+
+    pub fn component_type(&self) -> ComponentType {
+        self.component_type
+    }
+
+    pub fn values(&self) -> &[String] {
+        &self.values
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ModalSubmitInteractionData {
+    custom_id: String,
+    components: Vec<ModalActionRow>,
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ModalSubmitInteractionData {
+    pub fn custom_id(&self) -> &str {
+        &self.custom_id
+    }
+
+    pub fn components(&self) -> &[ModalActionRow] {
+        &self.components
+    }
+
+    pub fn text_inputs(&self) -> impl Iterator<Item = &ModalTextInput> {
+        self.components.iter().flat_map(|row| row.components.iter())
+    }
+
+    pub fn text_input(&self, custom_id: &str) -> Option<&ModalTextInput> {
+        self.text_inputs()
+            .find(|input| input.custom_id == custom_id)
+    }
+
+    pub fn value(&self, custom_id: &str) -> Option<&str> {
+        self.text_input(custom_id).map(|input| input.value())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ModalActionRow {
+    #[serde(rename = "type")]
+    ty: ComponentType,
+    components: Vec<ModalTextInput>,
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ModalActionRow {
+    pub fn component_type(&self) -> ComponentType {
+        self.ty
+    }
+
+    pub fn components(&self) -> &[ModalTextInput] {
+        &self.components
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ModalTextInput {
+    #[serde(rename = "type")]
+    ty: ComponentType,
+    custom_id: String,
+    #[serde(default)]
+    value: String,
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ModalTextInput {
+    pub fn component_type(&self) -> ComponentType {
+        self.ty
+    }
+
+    pub fn custom_id(&self) -> &str {
+        &self.custom_id
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ComponentType {
+    ActionRow,
+    Button,
+    StringSelect,
+    TextInput,
+    UserSelect,
+    RoleSelect,
+    MentionableSelect,
+    ChannelSelect,
+}
+
+impl ComponentType {
+    pub fn from_u8(ty: u8) -> Option<Self> {
+        use ComponentType::*;
+
+        Some(match ty {
+            1 => ActionRow,
+            2 => Button,
+            3 => StringSelect,
+            4 => TextInput,
+            5 => UserSelect,
+            6 => RoleSelect,
+            7 => MentionableSelect,
+            8 => ChannelSelect,
+            _ => return None,
+        })
+    }
+
+    pub fn as_u8(self) -> u8 {
+        use ComponentType::*;
+
+        match self {
+            ActionRow => 1,
+            Button => 2,
+            StringSelect => 3,
+            TextInput => 4,
+            UserSelect => 5,
+            RoleSelect => 6,
+            MentionableSelect => 7,
+            ChannelSelect => 8,
+        }
+    }
+}
+
+impl Serialize for ComponentType {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        s.serialize_u8(self.as_u8())
+    }
+}
+
+impl<'de> Deserialize<'de> for ComponentType {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let ty = u8::deserialize(d)?;
+        Self::from_u8(ty).ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(ty as u64),
+                &"a valid component type",
+            )
+        })
+    }
+}
+
+fn discriminator<'de, D>(d: D) -> Result<String, D::Error>
 where
     D: serde::Deserializer<'de>,
 {
-    #[derive(Deserialize)]
-    struct ApplicationCommand {
-        #[serde(rename = "type")]
-        ty: i32,
-        guild_id: String,
-        channel_id: String,
-        member: GuildMember,
-        data: ApplicationCommandInteractionData,
-    }
-
-    let ping = ApplicationCommand::deserialize(d)?;
-    if ping.ty == 2 {
-        Ok((ping.guild_id, ping.channel_id, ping.member, ping.data))
+    let discriminator = String::deserialize(d)?;
+    let valid = !discriminator.is_empty()
+        && discriminator.len() <= 4
+        && discriminator.bytes().all(|b| b.is_ascii_digit());
+    if valid {
+        Ok(discriminator)
     } else {
-        Err(serde::de::Error::custom("Not a ApplicationCommand type"))
+        Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Str(&discriminator),
+            &"a discriminator of up to four digits",
+        ))
     }
 }
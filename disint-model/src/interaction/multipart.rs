@@ -0,0 +1,122 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+use super::response::{File, InteractionResponse};
+
+#[derive(Clone, Debug)]
+pub struct Multipart {
+    boundary: String,
+    body: Vec<u8>,
+}
+
+impl Multipart {
+    pub fn boundary(&self) -> &str {
+        &self.boundary
+    }
+
+    pub fn content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary)
+    }
+
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    pub fn into_body(self) -> Vec<u8> {
+        self.body
+    }
+}
+
+impl InteractionResponse {
+    pub fn to_multipart(&self) -> serde_json::Result<Multipart> {
+        let payload = serde_json::to_vec(self)?;
+        let files = self.files();
+        let boundary = loop {
+            let boundary = random_boundary();
+            let conflicts = contains(&payload, boundary.as_bytes())
+                || files
+                    .iter()
+                    .any(|file| contains(file.data(), boundary.as_bytes()));
+            if !conflicts {
+                break boundary;
+            }
+        };
+
+        let mut body = Vec::new();
+        write_part_header(
+            &mut body,
+            &boundary,
+            "payload_json",
+            None,
+            "application/json",
+        );
+        body.extend_from_slice(&payload);
+        body.extend_from_slice(b"\r\n");
+        for (idx, file) in files.iter().enumerate() {
+            write_file(&mut body, &boundary, idx, file);
+        }
+        body.extend_from_slice(b"--");
+        body.extend_from_slice(boundary.as_bytes());
+        body.extend_from_slice(b"--\r\n");
+
+        Ok(Multipart { boundary, body })
+    }
+}
+
+fn write_file(body: &mut Vec<u8>, boundary: &str, idx: usize, file: &File) {
+    let name = format!("files[{}]", idx);
+    write_part_header(
+        body,
+        boundary,
+        &name,
+        Some(file.filename()),
+        file.mime_type(),
+    );
+    body.extend_from_slice(file.data());
+    body.extend_from_slice(b"\r\n");
+}
+
+fn write_part_header(
+    body: &mut Vec<u8>,
+    boundary: &str,
+    name: &str,
+    filename: Option<&str>,
+    content_type: &str,
+) {
+    body.extend_from_slice(b"--");
+    body.extend_from_slice(boundary.as_bytes());
+    body.extend_from_slice(b"\r\nContent-Disposition: form-data; name=\"");
+    body.extend_from_slice(escape(name).as_bytes());
+    body.push(b'"');
+    if let Some(filename) = filename {
+        body.extend_from_slice(b"; filename=\"");
+        body.extend_from_slice(escape(filename).as_bytes());
+        body.push(b'"');
+    }
+    body.extend_from_slice(b"\r\nContent-Type: ");
+    body.extend_from_slice(content_type.as_bytes());
+    body.extend_from_slice(b"\r\n\r\n");
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn random_boundary() -> String {
+    let state = RandomState::new();
+    let mut hasher = state.build_hasher();
+    hasher.write_u8(0);
+    let high = hasher.finish();
+    hasher.write_u8(1);
+    let low = hasher.finish();
+    format!("disint-{:016x}{:016x}", high, low)
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
@@ -0,0 +1,318 @@
+use std::fmt;
+
+use super::resolved::{Attachment, PartialChannel, PartialGuildMember, Role};
+use super::{
+    ApplicationCommandInteractionData, ApplicationCommandInteractionDataOption,
+    ApplicationCommandOptionType, User,
+};
+use crate::{OptionValue, Snowflake};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum OptionError {
+    Missing {
+        name: String,
+    },
+    WrongType {
+        name: String,
+        expected: &'static str,
+    },
+    Unresolved {
+        name: String,
+    },
+    UnknownSubcommand {
+        name: String,
+    },
+    UnknownChoice {
+        name: String,
+        value: String,
+    },
+}
+
+impl OptionError {
+    pub fn name(&self) -> &str {
+        match self {
+            OptionError::Missing { name }
+            | OptionError::WrongType { name, .. }
+            | OptionError::Unresolved { name }
+            | OptionError::UnknownSubcommand { name }
+            | OptionError::UnknownChoice { name, .. } => name,
+        }
+    }
+
+    pub fn is_missing(&self) -> bool {
+        matches!(self, OptionError::Missing { .. })
+    }
+}
+
+impl std::error::Error for OptionError {}
+
+impl fmt::Display for OptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptionError::Missing { name } => write!(f, "missing option `{}`", name),
+            OptionError::WrongType { name, expected } => {
+                write!(f, "option `{}` is not of type {}", name, expected)
+            }
+            OptionError::Unresolved { name } => {
+                write!(f, "option `{}` is missing from resolved data", name)
+            }
+            OptionError::UnknownSubcommand { name } if name.is_empty() => {
+                f.write_str("missing subcommand")
+            }
+            OptionError::UnknownSubcommand { name } => write!(f, "unknown subcommand `{}`", name),
+            OptionError::UnknownChoice { name, value } => {
+                write!(f, "option `{}` has unknown choice `{}`", name, value)
+            }
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, OptionError>;
+
+impl ApplicationCommandInteractionDataOption {
+    pub fn name(&self) -> &str {
+        match self {
+            ApplicationCommandInteractionDataOption::Value { name, .. }
+            | ApplicationCommandInteractionDataOption::Subcommand { name, .. }
+            | ApplicationCommandInteractionDataOption::SubcommandGroup { name, .. } => name,
+        }
+    }
+
+    pub fn option_type(&self) -> ApplicationCommandOptionType {
+        match self {
+            ApplicationCommandInteractionDataOption::Value { ty, .. } => *ty,
+            ApplicationCommandInteractionDataOption::Subcommand { .. } => {
+                ApplicationCommandOptionType::SubCommand
+            }
+            ApplicationCommandInteractionDataOption::SubcommandGroup { .. } => {
+                ApplicationCommandOptionType::SubCommandGroup
+            }
+        }
+    }
+
+    pub fn options(&self) -> &[ApplicationCommandInteractionDataOption] {
+        match self {
+            ApplicationCommandInteractionDataOption::Value { .. } => &[],
+            ApplicationCommandInteractionDataOption::Subcommand { options, .. }
+            | ApplicationCommandInteractionDataOption::SubcommandGroup { options, .. } => options,
+        }
+    }
+
+    pub fn value(&self) -> Option<&OptionValue> {
+        match self {
+            ApplicationCommandInteractionDataOption::Value { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl ApplicationCommandInteractionData {
+    pub fn subcommand_path(&self) -> Vec<&str> {
+        let mut path = Vec::new();
+        let mut options = self.options.as_slice();
+        while let Some((name, subcommand_options)) = find_subcommand(options) {
+            path.push(name);
+            options = subcommand_options;
+        }
+        path
+    }
+
+    pub fn is_subcommand(&self, path: &[&str]) -> bool {
+        self.subcommand_path() == path
+    }
+
+    pub fn leaf_options(&self) -> &[ApplicationCommandInteractionDataOption] {
+        let mut options = self.options.as_slice();
+        while let Some((_, subcommand_options)) = find_subcommand(options) {
+            options = subcommand_options;
+        }
+        options
+    }
+
+    pub fn find(&self, name: &str) -> Option<&OptionValue> {
+        self.leaf_options().iter().find_map(|option| match option {
+            ApplicationCommandInteractionDataOption::Value {
+                name: option_name,
+                value,
+                ..
+            } if option_name == name => Some(value),
+            _ => None,
+        })
+    }
+
+    pub fn get(&self, name: &str) -> Result<&OptionValue> {
+        self.find(name).ok_or_else(|| OptionError::Missing {
+            name: name.to_owned(),
+        })
+    }
+
+    pub fn get_str(&self, name: &str) -> Result<&str> {
+        self.get_typed(name, "string", OptionValue::try_as_str)
+    }
+
+    pub fn get_int(&self, name: &str) -> Result<i64> {
+        self.get_typed(name, "integer", OptionValue::try_as_int)
+    }
+
+    pub fn get_bool(&self, name: &str) -> Result<bool> {
+        self.get_typed(name, "boolean", OptionValue::try_as_bool)
+    }
+
+    pub fn get_number(&self, name: &str) -> Result<f64> {
+        self.get_typed(name, "number", OptionValue::try_as_number)
+    }
+
+    pub fn get_user_id(&self, name: &str) -> Result<Snowflake> {
+        self.get_typed(name, "user", OptionValue::try_as_user)
+    }
+
+    pub fn get_channel_id(&self, name: &str) -> Result<Snowflake> {
+        self.get_typed(name, "channel", OptionValue::try_as_channel)
+    }
+
+    pub fn get_role_id(&self, name: &str) -> Result<Snowflake> {
+        self.get_typed(name, "role", OptionValue::try_as_role)
+    }
+
+    pub fn get_mentionable_id(&self, name: &str) -> Result<Snowflake> {
+        self.get_typed(name, "mentionable", OptionValue::try_as_mentionable)
+    }
+
+    pub fn get_attachment_id(&self, name: &str) -> Result<Snowflake> {
+        self.get_typed(name, "attachment", OptionValue::try_as_attachment)
+    }
+
+    pub fn get_user(&self, name: &str) -> Result<&User> {
+        let id = self.get_user_id(name)?;
+        self.resolved.user(id).ok_or_else(|| unresolved(name))
+    }
+
+    pub fn get_member(&self, name: &str) -> Result<&PartialGuildMember> {
+        let id = self.get_user_id(name)?;
+        self.resolved.member(id).ok_or_else(|| unresolved(name))
+    }
+
+    pub fn get_channel(&self, name: &str) -> Result<&PartialChannel> {
+        let id = self.get_channel_id(name)?;
+        self.resolved.channel(id).ok_or_else(|| unresolved(name))
+    }
+
+    pub fn get_role(&self, name: &str) -> Result<&Role> {
+        let id = self.get_role_id(name)?;
+        self.resolved.role(id).ok_or_else(|| unresolved(name))
+    }
+
+    pub fn get_attachment(&self, name: &str) -> Result<&Attachment> {
+        let id = self.get_attachment_id(name)?;
+        self.resolved.attachment(id).ok_or_else(|| unresolved(name))
+    }
+
+    fn get_typed<'a, T>(
+        &'a self,
+        name: &str,
+        expected: &'static str,
+        f: impl FnOnce(&'a OptionValue) -> Option<T>,
+    ) -> Result<T> {
+        f(self.get(name)?).ok_or_else(|| OptionError::WrongType {
+            name: name.to_owned(),
+            expected,
+        })
+    }
+}
+
+pub trait CommandOptions: Sized {
+    fn from_data(data: &ApplicationCommandInteractionData) -> Result<Self>;
+}
+
+pub trait FromOption: Sized {
+    fn from_option(data: &ApplicationCommandInteractionData, name: &str) -> Result<Self>;
+}
+
+impl FromOption for String {
+    fn from_option(data: &ApplicationCommandInteractionData, name: &str) -> Result<Self> {
+        data.get_str(name).map(str::to_owned)
+    }
+}
+
+impl FromOption for i64 {
+    fn from_option(data: &ApplicationCommandInteractionData, name: &str) -> Result<Self> {
+        data.get_int(name)
+    }
+}
+
+impl FromOption for f64 {
+    fn from_option(data: &ApplicationCommandInteractionData, name: &str) -> Result<Self> {
+        data.get_number(name)
+    }
+}
+
+impl FromOption for bool {
+    fn from_option(data: &ApplicationCommandInteractionData, name: &str) -> Result<Self> {
+        data.get_bool(name)
+    }
+}
+
+impl FromOption for Snowflake {
+    fn from_option(data: &ApplicationCommandInteractionData, name: &str) -> Result<Self> {
+        data.get_typed(name, "snowflake", OptionValue::try_as_snowflake)
+    }
+}
+
+impl FromOption for User {
+    fn from_option(data: &ApplicationCommandInteractionData, name: &str) -> Result<Self> {
+        data.get_user(name).cloned()
+    }
+}
+
+impl FromOption for PartialGuildMember {
+    fn from_option(data: &ApplicationCommandInteractionData, name: &str) -> Result<Self> {
+        data.get_member(name).cloned()
+    }
+}
+
+impl FromOption for PartialChannel {
+    fn from_option(data: &ApplicationCommandInteractionData, name: &str) -> Result<Self> {
+        data.get_channel(name).cloned()
+    }
+}
+
+impl FromOption for Role {
+    fn from_option(data: &ApplicationCommandInteractionData, name: &str) -> Result<Self> {
+        data.get_role(name).cloned()
+    }
+}
+
+impl FromOption for Attachment {
+    fn from_option(data: &ApplicationCommandInteractionData, name: &str) -> Result<Self> {
+        data.get_attachment(name).cloned()
+    }
+}
+
+impl<T: FromOption> FromOption for Option<T> {
+    fn from_option(data: &ApplicationCommandInteractionData, name: &str) -> Result<Self> {
+        match T::from_option(data, name) {
+            Ok(v) => Ok(Some(v)),
+            Err(OptionError::Missing { .. }) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+fn find_subcommand(
+    options: &[ApplicationCommandInteractionDataOption],
+) -> Option<(&str, &[ApplicationCommandInteractionDataOption])> {
+    options.iter().find_map(|option| match option {
+        ApplicationCommandInteractionDataOption::Subcommand { name, options }
+        | ApplicationCommandInteractionDataOption::SubcommandGroup { name, options } => {
+            Some((name.as_str(), options.as_slice()))
+        }
+        _ => None,
+    })
+}
+
+fn unresolved(name: &str) -> OptionError {
+    OptionError::Unresolved {
+        name: name.to_owned(),
+    }
+}
@@ -0,0 +1,293 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Message, User};
+use crate::{Color, Permissions, Snowflake};
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Resolved {
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    users: HashMap<Snowflake, User>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    members: HashMap<Snowflake, PartialGuildMember>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    roles: HashMap<Snowflake, Role>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    channels: HashMap<Snowflake, PartialChannel>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    messages: HashMap<Snowflake, Message>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    attachments: HashMap<Snowflake, Attachment>,
+}
+
+impl Resolved {
+    pub fn user(&self, id: Snowflake) -> Option<&User> {
+        self.users.get(&id)
+    }
+
+    pub fn member(&self, id: Snowflake) -> Option<&PartialGuildMember> {
+        self.members.get(&id)
+    }
+
+    pub fn role(&self, id: Snowflake) -> Option<&Role> {
+        self.roles.get(&id)
+    }
+
+    pub fn channel(&self, id: Snowflake) -> Option<&PartialChannel> {
+        self.channels.get(&id)
+    }
+
+    pub fn message(&self, id: Snowflake) -> Option<&Message> {
+        self.messages.get(&id)
+    }
+
+    pub fn attachment(&self, id: Snowflake) -> Option<&Attachment> {
+        self.attachments.get(&id)
+    }
+
+    pub fn users(&self) -> impl Iterator<Item = &User> {
+        self.users.values()
+    }
+
+    pub fn members(&self) -> impl Iterator<Item = (Snowflake, &PartialGuildMember)> {
+        self.members.iter().map(|(id, member)| (*id, member))
+    }
+
+    pub fn roles(&self) -> impl Iterator<Item = &Role> {
+        self.roles.values()
+    }
+
+    pub fn channels(&self) -> impl Iterator<Item = &PartialChannel> {
+        self.channels.values()
+    }
+
+    pub fn messages(&self) -> impl Iterator<Item = &Message> {
+        self.messages.values()
+    }
+
+    pub fn attachments(&self) -> impl Iterator<Item = &Attachment> {
+        self.attachments.values()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.users.is_empty()
+            && self.members.is_empty()
+            && self.roles.is_empty()
+            && self.channels.is_empty()
+            && self.messages.is_empty()
+            && self.attachments.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PartialGuildMember {
+    nick: Option<String>,
+    roles: Vec<Snowflake>,
+    #[serde(serialize_with = "crate::timestamp::serialize")]
+    joined_at: chrono::DateTime<chrono::Utc>,
+    #[serde(serialize_with = "crate::timestamp::serialize_option")]
+    premium_since: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permissions: Option<Permissions>,
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl PartialGuildMember {
+    pub fn nick(&self) -> Option<&str> {
+        self.nick.as_deref()
+    }
+
+    pub fn roles(&self) -> &[Snowflake] {
+        &self.roles
+    }
+
+    pub fn joined_at(&self) -> chrono::DateTime<chrono::Utc> {
+        self.joined_at
+    }
+
+    pub fn is_boosting(&self) -> bool {
+        self.premium_since.is_some()
+    }
+
+    pub fn boosting_since(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.premium_since
+    }
+
+    pub fn permissions(&self) -> Option<Permissions> {
+        self.permissions
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Role {
+    id: Snowflake,
+    name: String,
+    color: Color,
+    hoist: bool,
+    icon: Option<String>,
+    unicode_emoji: Option<String>,
+    position: i32,
+    permissions: Permissions,
+    managed: bool,
+    mentionable: bool,
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Role {
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    pub fn is_hoisted(&self) -> bool {
+        self.hoist
+    }
+
+    pub fn icon(&self) -> Option<&str> {
+        self.icon.as_deref()
+    }
+
+    pub fn unicode_emoji(&self) -> Option<&str> {
+        self.unicode_emoji.as_deref()
+    }
+
+    pub fn position(&self) -> i32 {
+        self.position
+    }
+
+    pub fn permissions(&self) -> Permissions {
+        self.permissions
+    }
+
+    pub fn is_managed(&self) -> bool {
+        self.managed
+    }
+
+    pub fn is_mentionable(&self) -> bool {
+        self.mentionable
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PartialChannel {
+    id: Snowflake,
+    #[serde(rename = "type")]
+    ty: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    // Discord sends `null` for channels without a parent, and leaves the
+    // field out for channels that can't have one.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "nullable"
+    )]
+    parent_id: Option<Option<Snowflake>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permissions: Option<Permissions>,
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl PartialChannel {
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    pub fn channel_type(&self) -> i32 {
+        self.ty
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn parent_id(&self) -> Option<Snowflake> {
+        self.parent_id.flatten()
+    }
+
+    pub fn permissions(&self) -> Option<Permissions> {
+        self.permissions
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Attachment {
+    id: Snowflake,
+    filename: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+    size: u64,
+    url: String,
+    proxy_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    height: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<u32>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    ephemeral: bool,
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Attachment {
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn proxy_url(&self) -> &str {
+        &self.proxy_url
+    }
+
+    pub fn height(&self) -> Option<u32> {
+        self.height
+    }
+
+    pub fn width(&self) -> Option<u32> {
+        self.width
+    }
+
+    pub fn is_ephemeral(&self) -> bool {
+        self.ephemeral
+    }
+}
+
+fn nullable<'de, D, T>(d: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::deserialize(d).map(Some)
+}
@@ -1,14 +1,43 @@
-use derive_builder::Builder;
-use serde::Serialize;
+use std::fmt;
 
-use super::embed::Embed;
+use serde::{Deserialize, Serialize};
+
+use super::components::{self, ComponentError, Components, TextInput};
+use super::embed::{self, Embed, EmbedError};
+use crate::command::ApplicationCommandOptionChoice;
+use crate::{MessageFlags, Snowflake};
 
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum InteractionResponse {
     Pong,
     ChannelMessageWithSource(ApplicationCommandCallbackData),
-    DeferredChannelMessageWithSource,
+    DeferredChannelMessageWithSource(MessageFlags),
+    DeferredUpdateMessage,
+    UpdateMessage(ApplicationCommandCallbackData),
+    ApplicationCommandAutocompleteResult(AutocompleteCallbackData),
+    Modal(ModalCallbackData),
+    LaunchActivity,
+}
+
+impl InteractionResponse {
+    pub fn files(&self) -> &[File] {
+        match self {
+            InteractionResponse::ChannelMessageWithSource(data)
+            | InteractionResponse::UpdateMessage(data) => &data.files,
+            _ => &[],
+        }
+    }
+
+    pub fn embeds(&self) -> &[Embed] {
+        match self {
+            InteractionResponse::ChannelMessageWithSource(data)
+            | InteractionResponse::UpdateMessage(data) => {
+                data.embeds.as_deref().unwrap_or_default()
+            }
+            _ => &[],
+        }
+    }
 }
 
 impl Serialize for InteractionResponse {
@@ -20,20 +49,329 @@ impl Serialize for InteractionResponse {
     }
 }
 
-#[derive(Debug, Serialize)]
+impl<'de> Deserialize<'de> for InteractionResponse {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+        use InteractionResponse::*;
+
+        #[derive(Deserialize)]
+        struct InteractionResponseRaw {
+            #[serde(rename = "type")]
+            ty: u64,
+            #[serde(default)]
+            data: Option<serde_json::Value>,
+        }
+
+        fn data<T, E>(data: Option<serde_json::Value>) -> Result<T, E>
+        where
+            T: serde::de::DeserializeOwned,
+            E: Error,
+        {
+            let data = data.ok_or_else(|| E::missing_field("data"))?;
+            serde_json::from_value(data).map_err(E::custom)
+        }
+
+        let InteractionResponseRaw { ty, data: raw } = InteractionResponseRaw::deserialize(d)?;
+        Ok(match ty {
+            1 => Pong,
+            4 => ChannelMessageWithSource(data(raw)?),
+            5 => match raw {
+                Some(raw) => {
+                    let DeferredCallbackData { flags } = data(Some(raw))?;
+                    DeferredChannelMessageWithSource(flags)
+                }
+                None => DeferredChannelMessageWithSource(MessageFlags::empty()),
+            },
+            6 => DeferredUpdateMessage,
+            7 => UpdateMessage(data(raw)?),
+            8 => ApplicationCommandAutocompleteResult(data(raw)?),
+            9 => Modal(data(raw)?),
+            12 => LaunchActivity,
+            ty => {
+                return Err(D::Error::invalid_value(
+                    serde::de::Unexpected::Unsigned(ty),
+                    &"a valid interaction callback type",
+                ))
+            }
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ApplicationCommandCallbackData {
+    #[serde(skip_serializing_if = "Option::is_none")]
     tts: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     embeds: Option<Vec<Embed>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     allowed_mentions: Option<AllowedMentions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Components>,
+    #[serde(default, skip_serializing_if = "MessageFlags::is_empty")]
+    flags: MessageFlags,
+    #[serde(
+        rename = "attachments",
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_attachments",
+        deserialize_with = "deserialize_attachments"
+    )]
+    files: Vec<File>,
+}
+
+fn serialize_attachments<S>(files: &[File], s: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    #[derive(Serialize)]
+    struct AttachmentInner<'a> {
+        id: usize,
+        filename: &'a str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<&'a str>,
+    }
+
+    s.collect_seq(files.iter().enumerate().map(|(id, file)| AttachmentInner {
+        id,
+        filename: &file.filename,
+        description: file.description.as_deref(),
+    }))
+}
+
+fn deserialize_attachments<'de, D>(d: D) -> Result<Vec<File>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct AttachmentInner {
+        filename: String,
+        #[serde(default)]
+        description: Option<String>,
+    }
+
+    let attachments = Vec::<AttachmentInner>::deserialize(d)?;
+    Ok(attachments
+        .into_iter()
+        .map(|attachment| File {
+            filename: attachment.filename,
+            description: attachment.description,
+            content_type: None,
+            data: Vec::new(),
+        })
+        .collect())
+}
+
+#[derive(Clone, Debug)]
+pub struct File {
+    filename: String,
+    description: Option<String>,
+    content_type: Option<String>,
+    data: Vec<u8>,
+}
+
+impl File {
+    pub fn new(filename: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            filename: filename.into(),
+            description: None,
+            content_type: None,
+            data: data.into(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn mime_type(&self) -> &str {
+        self.content_type
+            .as_deref()
+            .unwrap_or("application/octet-stream")
+    }
+
+    pub fn attachment_url(&self) -> String {
+        format!("attachment://{}", self.filename)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DeferredCallbackData {
+    #[serde(default)]
+    flags: MessageFlags,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AutocompleteCallbackData {
+    choices: Vec<ApplicationCommandOptionChoice>,
+}
+
+#[derive(Debug)]
+pub struct ModalCallbackData {
+    custom_id: String,
+    title: String,
+    components: Vec<TextInput>,
+}
+
+impl Serialize for ModalCallbackData {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct ActionRow<'a> {
+            #[serde(rename = "type")]
+            ty: i32,
+            components: [&'a TextInput; 1],
+        }
+
+        #[derive(Serialize)]
+        struct ModalCallbackDataInner<'a> {
+            custom_id: &'a str,
+            title: &'a str,
+            components: Vec<ActionRow<'a>>,
+        }
+
+        let components = self
+            .components
+            .iter()
+            .map(|text_input| ActionRow {
+                ty: 1,
+                components: [text_input],
+            })
+            .collect();
+
+        ModalCallbackDataInner {
+            custom_id: &self.custom_id,
+            title: &self.title,
+            components,
+        }
+        .serialize(s)
+    }
 }
 
-#[derive(Debug, Default, Builder)]
-#[builder(default)]
+impl<'de> Deserialize<'de> for ModalCallbackData {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct ActionRow {
+            components: Vec<TextInput>,
+        }
+
+        #[derive(Deserialize)]
+        struct ModalCallbackDataInner {
+            custom_id: String,
+            title: String,
+            components: Vec<ActionRow>,
+        }
+
+        let inner = ModalCallbackDataInner::deserialize(d)?;
+        Ok(Self {
+            custom_id: inner.custom_id,
+            title: inner.title,
+            components: inner
+                .components
+                .into_iter()
+                .flat_map(|row| row.components)
+                .collect(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum AllowedMentionsError {
+    Conflict { kind: &'static str },
+    TooManyIds { kind: &'static str, count: usize },
+}
+
+impl std::error::Error for AllowedMentionsError {}
+
+impl fmt::Display for AllowedMentionsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AllowedMentionsError::Conflict { kind } => write!(
+                f,
+                "cannot allow all {} and a list of {} at the same time",
+                kind, kind
+            ),
+            AllowedMentionsError::TooManyIds { kind, count } => write!(
+                f,
+                "{} {} given, at most {} allowed",
+                count, kind, MAX_ALLOWED_MENTION_IDS
+            ),
+        }
+    }
+}
+
+const MAX_ALLOWED_MENTION_IDS: usize = 100;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AllowedMentions {
+    everyone: bool,
     roles: AllowedMentionsKind,
     users: AllowedMentionsKind,
-    deny_mention_everyone: bool,
+    replied_user: bool,
+}
+
+impl AllowedMentions {
+    pub fn builder() -> AllowedMentionsBuilder {
+        AllowedMentionsBuilder::default()
+    }
+
+    pub fn none() -> Self {
+        Self {
+            everyone: false,
+            roles: AllowedMentionsKind::None,
+            users: AllowedMentionsKind::None,
+            replied_user: false,
+        }
+    }
+
+    pub fn all() -> Self {
+        Self {
+            everyone: true,
+            roles: AllowedMentionsKind::All,
+            users: AllowedMentionsKind::All,
+            replied_user: true,
+        }
+    }
+
+    pub fn everyone(&self) -> bool {
+        self.everyone
+    }
+
+    pub fn roles(&self) -> &AllowedMentionsKind {
+        &self.roles
+    }
+
+    pub fn users(&self) -> &AllowedMentionsKind {
+        &self.users
+    }
+
+    pub fn replied_user(&self) -> bool {
+        self.replied_user
+    }
 }
 
 impl Serialize for AllowedMentions {
@@ -44,59 +382,207 @@ impl Serialize for AllowedMentions {
         #[derive(Default, Serialize)]
         struct AllowedMentionsInner<'a> {
             parse: Vec<&'static str>,
-            roles: Option<&'a [String]>,
-            users: Option<&'a [String]>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            roles: Option<&'a [Snowflake]>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            users: Option<&'a [Snowflake]>,
+            #[serde(skip_serializing_if = "std::ops::Not::not")]
+            replied_user: bool,
         }
 
-        let mut inner = AllowedMentionsInner::default();
-        if !self.deny_mention_everyone {
+        let mut inner = AllowedMentionsInner {
+            replied_user: self.replied_user,
+            ..Default::default()
+        };
+        if self.everyone {
             inner.parse.push("everyone");
         }
         match &self.roles {
             AllowedMentionsKind::All => inner.parse.push("roles"),
-            AllowedMentionsKind::List(l) => inner.roles = Some(&*l),
-            _ => {}
+            AllowedMentionsKind::List(l) => inner.roles = Some(l),
+            AllowedMentionsKind::None => {}
         }
         match &self.users {
             AllowedMentionsKind::All => inner.parse.push("users"),
-            AllowedMentionsKind::List(l) => inner.users = Some(&*l),
-            _ => {}
+            AllowedMentionsKind::List(l) => inner.users = Some(l),
+            AllowedMentionsKind::None => {}
         }
 
         inner.serialize(s)
     }
 }
 
-#[derive(Clone, Debug)]
-pub enum AllowedMentionsKind {
-    All,
-    List(Vec<String>),
-    None,
-}
+impl<'de> Deserialize<'de> for AllowedMentions {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "lowercase")]
+        enum ParseKind {
+            Everyone,
+            Roles,
+            Users,
+        }
 
-impl AllowedMentionsKind {
-    pub fn new() -> Self {
-        Self::default()
+        #[derive(Deserialize)]
+        struct AllowedMentionsInner {
+            #[serde(default)]
+            parse: Vec<ParseKind>,
+            #[serde(default)]
+            roles: Option<Vec<Snowflake>>,
+            #[serde(default)]
+            users: Option<Vec<Snowflake>>,
+            #[serde(default)]
+            replied_user: bool,
+        }
+
+        let inner = AllowedMentionsInner::deserialize(d)?;
+        let mut builder = AllowedMentions::builder().replied_user(inner.replied_user);
+        for kind in inner.parse {
+            builder = match kind {
+                ParseKind::Everyone => builder.everyone(true),
+                ParseKind::Roles => builder.all_roles(),
+                ParseKind::Users => builder.all_users(),
+            };
+        }
+        if let Some(roles) = inner.roles {
+            builder = builder.roles(roles);
+        }
+        if let Some(users) = inner.users {
+            builder = builder.users(users);
+        }
+        builder.build().map_err(serde::de::Error::custom)
     }
 }
 
-impl std::iter::FromIterator<String> for AllowedMentionsKind {
-    fn from_iter<T: IntoIterator<Item = String>>(iter: T) -> Self {
-        Self::List(<Vec<_> as std::iter::FromIterator<_>>::from_iter(iter))
-    }
+#[derive(Debug, Default)]
+pub struct AllowedMentionsBuilder {
+    everyone: bool,
+    roles: AllowedMentionsKind,
+    users: AllowedMentionsKind,
+    replied_user: bool,
+    conflict: Option<&'static str>,
 }
 
-impl Default for AllowedMentionsKind {
-    fn default() -> Self {
-        Self::All
+impl AllowedMentionsBuilder {
+    pub fn everyone(mut self, everyone: bool) -> Self {
+        self.everyone = everyone;
+        self
+    }
+
+    pub fn all_roles(mut self) -> Self {
+        self.roles = Self::allow_all(self.roles, "roles", &mut self.conflict);
+        self
+    }
+
+    pub fn role(self, id: Snowflake) -> Self {
+        self.roles(std::iter::once(id))
+    }
+
+    pub fn roles(mut self, ids: impl IntoIterator<Item = Snowflake>) -> Self {
+        self.roles = Self::allow_list(self.roles, ids, "roles", &mut self.conflict);
+        self
+    }
+
+    pub fn all_users(mut self) -> Self {
+        self.users = Self::allow_all(self.users, "users", &mut self.conflict);
+        self
+    }
+
+    pub fn user(self, id: Snowflake) -> Self {
+        self.users(std::iter::once(id))
+    }
+
+    pub fn users(mut self, ids: impl IntoIterator<Item = Snowflake>) -> Self {
+        self.users = Self::allow_list(self.users, ids, "users", &mut self.conflict);
+        self
+    }
+
+    pub fn replied_user(mut self, replied_user: bool) -> Self {
+        self.replied_user = replied_user;
+        self
+    }
+
+    pub fn build(self) -> Result<AllowedMentions, AllowedMentionsError> {
+        if let Some(kind) = self.conflict {
+            return Err(AllowedMentionsError::Conflict { kind });
+        }
+        for (kind, list) in [("roles", &self.roles), ("users", &self.users)] {
+            if let AllowedMentionsKind::List(l) = list {
+                if l.len() > MAX_ALLOWED_MENTION_IDS {
+                    return Err(AllowedMentionsError::TooManyIds {
+                        kind,
+                        count: l.len(),
+                    });
+                }
+            }
+        }
+
+        Ok(AllowedMentions {
+            everyone: self.everyone,
+            roles: self.roles,
+            users: self.users,
+            replied_user: self.replied_user,
+        })
+    }
+
+    fn allow_all(
+        current: AllowedMentionsKind,
+        kind: &'static str,
+        conflict: &mut Option<&'static str>,
+    ) -> AllowedMentionsKind {
+        if let AllowedMentionsKind::List(_) = current {
+            conflict.get_or_insert(kind);
+        }
+        AllowedMentionsKind::All
+    }
+
+    fn allow_list(
+        current: AllowedMentionsKind,
+        ids: impl IntoIterator<Item = Snowflake>,
+        kind: &'static str,
+        conflict: &mut Option<&'static str>,
+    ) -> AllowedMentionsKind {
+        let mut list = match current {
+            AllowedMentionsKind::List(l) => l,
+            AllowedMentionsKind::All => {
+                conflict.get_or_insert(kind);
+                Vec::new()
+            }
+            AllowedMentionsKind::None => Vec::new(),
+        };
+        for id in ids {
+            if !list.contains(&id) {
+                list.push(id);
+            }
+        }
+        AllowedMentionsKind::List(list)
     }
 }
 
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum AllowedMentionsKind {
+    All,
+    List(Vec<Snowflake>),
+    #[default]
+    None,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum InteractionCallbackData<'a> {
+    Deferred(DeferredCallbackData),
+    Message(&'a ApplicationCommandCallbackData),
+    Autocomplete(&'a AutocompleteCallbackData),
+    Modal(&'a ModalCallbackData),
+}
+
 #[derive(Serialize)]
 struct InteractionResponseInner<'a> {
     #[serde(rename = "type")]
     ty: i32,
-    data: Option<&'a ApplicationCommandCallbackData>,
+    data: Option<InteractionCallbackData<'a>>,
 }
 
 impl<'a> InteractionResponseInner<'a> {
@@ -105,8 +591,21 @@ impl<'a> InteractionResponseInner<'a> {
 
         let (ty, data) = match val {
             Pong => (1, None),
-            ChannelMessageWithSource(data) => (4, Some(data)),
-            DeferredChannelMessageWithSource => (5, None),
+            ChannelMessageWithSource(data) => (4, Some(InteractionCallbackData::Message(data))),
+            DeferredChannelMessageWithSource(flags) if flags.is_empty() => (5, None),
+            DeferredChannelMessageWithSource(flags) => (
+                5,
+                Some(InteractionCallbackData::Deferred(DeferredCallbackData {
+                    flags: *flags,
+                })),
+            ),
+            DeferredUpdateMessage => (6, None),
+            UpdateMessage(data) => (7, Some(InteractionCallbackData::Message(data))),
+            ApplicationCommandAutocompleteResult(data) => {
+                (8, Some(InteractionCallbackData::Autocomplete(data)))
+            }
+            Modal(data) => (9, Some(InteractionCallbackData::Modal(data))),
+            LaunchActivity => (12, None),
         };
 
         Self { ty, data }
@@ -120,13 +619,51 @@ pub struct InteractionResponseBuilder<State>(State);
 pub struct Pong;
 
 #[derive(Debug)]
-pub struct Deferred;
+pub struct Deferred {
+    flags: MessageFlags,
+}
+
+#[derive(Debug)]
+pub struct DeferredUpdate;
+
+#[derive(Debug, Default)]
+pub struct UpdateMessage {
+    content: Option<String>,
+    embeds: Option<Vec<Embed>>,
+    allowed_mentions: Option<AllowedMentions>,
+    components: Option<Components>,
+    files: Vec<File>,
+}
+
+#[derive(Debug, Default)]
+pub struct AutocompleteResult {
+    choices: Vec<ApplicationCommandOptionChoice>,
+}
+
+#[derive(Debug)]
+pub struct ModalNoInputs {
+    custom_id: String,
+    title: String,
+}
+
+#[derive(Debug)]
+pub struct Modal {
+    custom_id: String,
+    title: String,
+    components: Vec<TextInput>,
+}
+
+#[derive(Debug)]
+pub struct LaunchActivity;
 
 #[derive(Debug, Default)]
 pub struct ChannelMessageNoContent {
     tts: Option<bool>,
     embeds: Option<Vec<Embed>>,
     allowed_mentions: Option<AllowedMentions>,
+    components: Option<Components>,
+    flags: MessageFlags,
+    files: Vec<File>,
 }
 
 #[derive(Debug)]
@@ -135,6 +672,9 @@ pub struct ChannelMessage {
     tts: Option<bool>,
     embeds: Option<Vec<Embed>>,
     allowed_mentions: Option<AllowedMentions>,
+    components: Option<Components>,
+    flags: MessageFlags,
+    files: Vec<File>,
 }
 
 impl InteractionResponseBuilder<Pong> {
@@ -149,11 +689,180 @@ impl InteractionResponseBuilder<Pong> {
 
 impl InteractionResponseBuilder<Deferred> {
     pub fn deferred() -> Self {
-        Self(Deferred)
+        Self(Deferred {
+            flags: MessageFlags::empty(),
+        })
+    }
+
+    pub fn ephemeral(mut self) -> Self {
+        self.0.flags.insert(MessageFlags::EPHEMERAL);
+        self
+    }
+
+    pub fn finish(self) -> InteractionResponse {
+        InteractionResponse::DeferredChannelMessageWithSource(self.0.flags)
+    }
+}
+
+impl InteractionResponseBuilder<DeferredUpdate> {
+    pub fn deferred_update() -> Self {
+        Self(DeferredUpdate)
+    }
+
+    pub fn finish(self) -> InteractionResponse {
+        InteractionResponse::DeferredUpdateMessage
+    }
+}
+
+impl InteractionResponseBuilder<UpdateMessage> {
+    pub fn update_message() -> Self {
+        Self(UpdateMessage::default())
+    }
+
+    pub fn finish(self) -> Result<InteractionResponse, EmbedError> {
+        let UpdateMessage {
+            content,
+            embeds,
+            allowed_mentions,
+            components,
+            files,
+        } = self.0;
+
+        if let Some(embeds) = &embeds {
+            embed::validate_embeds(embeds)?;
+        }
+
+        let data = ApplicationCommandCallbackData {
+            tts: None,
+            content,
+            embeds,
+            allowed_mentions,
+            components,
+            flags: MessageFlags::empty(),
+            files,
+        };
+
+        Ok(InteractionResponse::UpdateMessage(data))
+    }
+
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.0.content = Some(content.into());
+        self
+    }
+
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.0.embeds.get_or_insert_with(Vec::new).push(embed);
+        self
+    }
+
+    pub fn clear_embeds(mut self) -> Self {
+        self.0.embeds = Some(Vec::new());
+        self
+    }
+
+    pub fn components(mut self, components: Components) -> Self {
+        self.0.components = Some(components);
+        self
+    }
+
+    pub fn file(mut self, file: File) -> Self {
+        self.0.files.push(file);
+        self
+    }
+
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.0.allowed_mentions = Some(allowed_mentions);
+        self
+    }
+}
+
+impl InteractionResponseBuilder<AutocompleteResult> {
+    pub fn autocomplete() -> Self {
+        Self(AutocompleteResult::default())
+    }
+
+    pub fn finish(self) -> InteractionResponse {
+        let data = AutocompleteCallbackData {
+            choices: self.0.choices,
+        };
+        InteractionResponse::ApplicationCommandAutocompleteResult(data)
+    }
+
+    pub fn choice(mut self, name: impl Into<String>, value: impl Into<crate::OptionValue>) -> Self {
+        self.0
+            .choices
+            .push(ApplicationCommandOptionChoice::new(name, value));
+        self
+    }
+
+    pub fn choices(
+        mut self,
+        choices: impl IntoIterator<Item = ApplicationCommandOptionChoice>,
+    ) -> Self {
+        self.0.choices.extend(choices);
+        self
+    }
+}
+
+impl InteractionResponseBuilder<ModalNoInputs> {
+    pub fn modal(custom_id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self(ModalNoInputs {
+            custom_id: custom_id.into(),
+            title: title.into(),
+        })
+    }
+
+    pub fn text_input(self, text_input: TextInput) -> InteractionResponseBuilder<Modal> {
+        let ModalNoInputs { custom_id, title } = self.0;
+
+        InteractionResponseBuilder(Modal {
+            custom_id,
+            title,
+            components: vec![text_input],
+        })
+    }
+}
+
+impl InteractionResponseBuilder<Modal> {
+    pub fn finish(self) -> Result<InteractionResponse, ComponentError> {
+        let Modal {
+            custom_id,
+            title,
+            components,
+        } = self.0;
+
+        if components.len() > components::MAX_ROWS {
+            return Err(ComponentError::TooManyRows {
+                count: components.len(),
+            });
+        }
+        components::check_custom_id(&custom_id)?;
+        for text_input in &components {
+            components::check_custom_id(text_input.custom_id())?;
+        }
+
+        let data = ModalCallbackData {
+            custom_id,
+            title,
+            components,
+        };
+
+        Ok(InteractionResponse::Modal(data))
+    }
+
+    pub fn text_input(mut self, text_input: TextInput) -> Self {
+        self.0.components.push(text_input);
+        self
+    }
+}
+
+impl InteractionResponseBuilder<LaunchActivity> {
+    pub fn launch_activity() -> Self {
+        Self(LaunchActivity)
     }
 
     pub fn finish(self) -> InteractionResponse {
-        InteractionResponse::DeferredChannelMessageWithSource
+        InteractionResponse::LaunchActivity
     }
 }
 
@@ -167,6 +876,9 @@ impl InteractionResponseBuilder<ChannelMessageNoContent> {
             tts,
             embeds,
             allowed_mentions,
+            components,
+            flags,
+            files,
         } = self.0;
 
         InteractionResponseBuilder(ChannelMessage {
@@ -174,6 +886,9 @@ impl InteractionResponseBuilder<ChannelMessageNoContent> {
             tts,
             embeds,
             allowed_mentions,
+            components,
+            flags,
+            files,
         })
     }
 
@@ -187,6 +902,9 @@ impl InteractionResponseBuilder<ChannelMessageNoContent> {
             tts,
             mut embeds,
             allowed_mentions,
+            components,
+            flags,
+            files,
         } = self.0;
 
         embeds
@@ -198,6 +916,53 @@ impl InteractionResponseBuilder<ChannelMessageNoContent> {
             tts,
             embeds,
             allowed_mentions,
+            components,
+            flags,
+            files,
+        })
+    }
+
+    pub fn components(self, components: Components) -> InteractionResponseBuilder<ChannelMessage> {
+        let ChannelMessageNoContent {
+            tts,
+            embeds,
+            allowed_mentions,
+            flags,
+            files,
+            ..
+        } = self.0;
+
+        InteractionResponseBuilder(ChannelMessage {
+            content: None,
+            tts,
+            embeds,
+            allowed_mentions,
+            components: Some(components),
+            flags,
+            files,
+        })
+    }
+
+    pub fn file(self, file: File) -> InteractionResponseBuilder<ChannelMessage> {
+        let ChannelMessageNoContent {
+            tts,
+            embeds,
+            allowed_mentions,
+            components,
+            flags,
+            mut files,
+        } = self.0;
+
+        files.push(file);
+
+        InteractionResponseBuilder(ChannelMessage {
+            content: None,
+            tts,
+            embeds,
+            allowed_mentions,
+            components,
+            flags,
+            files,
         })
     }
 
@@ -205,25 +970,50 @@ impl InteractionResponseBuilder<ChannelMessageNoContent> {
         self.0.allowed_mentions = Some(allowed_mentions);
         self
     }
+
+    pub fn ephemeral(mut self) -> Self {
+        self.0.flags.insert(MessageFlags::EPHEMERAL);
+        self
+    }
+
+    pub fn suppress_embeds(mut self) -> Self {
+        self.0.flags.insert(MessageFlags::SUPPRESS_EMBEDS);
+        self
+    }
+
+    pub fn suppress_notifications(mut self) -> Self {
+        self.0.flags.insert(MessageFlags::SUPPRESS_NOTIFICATIONS);
+        self
+    }
 }
 
 impl InteractionResponseBuilder<ChannelMessage> {
-    pub fn finish(self) -> InteractionResponse {
+    pub fn finish(self) -> Result<InteractionResponse, EmbedError> {
         let ChannelMessage {
             content,
             tts,
             embeds,
             allowed_mentions,
+            components,
+            flags,
+            files,
         } = self.0;
 
+        if let Some(embeds) = &embeds {
+            embed::validate_embeds(embeds)?;
+        }
+
         let data = ApplicationCommandCallbackData {
             tts,
             content,
             embeds,
             allowed_mentions,
+            components,
+            flags,
+            files,
         };
 
-        InteractionResponse::ChannelMessageWithSource(data)
+        Ok(InteractionResponse::ChannelMessageWithSource(data))
     }
 
     pub fn content(mut self, content: impl Into<String>) -> Self {
@@ -244,8 +1034,33 @@ impl InteractionResponseBuilder<ChannelMessage> {
         self
     }
 
+    pub fn components(mut self, components: Components) -> Self {
+        self.0.components = Some(components);
+        self
+    }
+
+    pub fn file(mut self, file: File) -> Self {
+        self.0.files.push(file);
+        self
+    }
+
     pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
         self.0.allowed_mentions = Some(allowed_mentions);
         self
     }
+
+    pub fn ephemeral(mut self) -> Self {
+        self.0.flags.insert(MessageFlags::EPHEMERAL);
+        self
+    }
+
+    pub fn suppress_embeds(mut self) -> Self {
+        self.0.flags.insert(MessageFlags::SUPPRESS_EMBEDS);
+        self
+    }
+
+    pub fn suppress_notifications(mut self) -> Self {
+        self.0.flags.insert(MessageFlags::SUPPRESS_NOTIFICATIONS);
+        self
+    }
 }
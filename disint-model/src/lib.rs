@@ -1,8 +1,20 @@
+mod color;
+mod message_flags;
 mod option_value;
+mod permissions;
+mod snowflake;
+mod timestamp;
 
-#[cfg(feature = "incomplete")]
 pub mod command;
 pub mod interaction;
 
+pub use color::{Color, ParseColorError};
+pub use interaction::options::CommandOptions;
 pub use interaction::{Interaction, InteractionResponseBuilder};
+pub use message_flags::MessageFlags;
 pub use option_value::OptionValue;
+pub use permissions::Permissions;
+pub use snowflake::Snowflake;
+
+#[cfg(feature = "derive")]
+pub use disint_derive::{CommandChoice, CommandDefinition, CommandOptions};
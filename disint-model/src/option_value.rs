@@ -1,8 +1,23 @@
-#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+use crate::interaction::ApplicationCommandOptionType;
+use crate::Snowflake;
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(untagged)]
 pub enum OptionValue {
     String(String),
-    Int(i32),
+    Int(i64),
+    Bool(bool),
+    Number(f64),
+    #[serde(skip_deserializing)]
+    User(Snowflake),
+    #[serde(skip_deserializing)]
+    Channel(Snowflake),
+    #[serde(skip_deserializing)]
+    Role(Snowflake),
+    #[serde(skip_deserializing)]
+    Mentionable(Snowflake),
+    #[serde(skip_deserializing)]
+    Attachment(Snowflake),
 }
 
 impl std::fmt::Display for OptionValue {
@@ -10,10 +25,53 @@ impl std::fmt::Display for OptionValue {
         match self {
             OptionValue::String(v) => v.fmt(f),
             OptionValue::Int(v) => v.fmt(f),
+            OptionValue::Bool(v) => v.fmt(f),
+            OptionValue::Number(v) => v.fmt(f),
+            OptionValue::User(v)
+            | OptionValue::Channel(v)
+            | OptionValue::Role(v)
+            | OptionValue::Mentionable(v)
+            | OptionValue::Attachment(v) => v.fmt(f),
         }
     }
 }
 
+impl From<String> for OptionValue {
+    fn from(v: String) -> Self {
+        OptionValue::String(v)
+    }
+}
+
+impl From<&str> for OptionValue {
+    fn from(v: &str) -> Self {
+        OptionValue::String(v.to_owned())
+    }
+}
+
+impl From<i32> for OptionValue {
+    fn from(v: i32) -> Self {
+        OptionValue::Int(i64::from(v))
+    }
+}
+
+impl From<i64> for OptionValue {
+    fn from(v: i64) -> Self {
+        OptionValue::Int(v)
+    }
+}
+
+impl From<bool> for OptionValue {
+    fn from(v: bool) -> Self {
+        OptionValue::Bool(v)
+    }
+}
+
+impl From<f64> for OptionValue {
+    fn from(v: f64) -> Self {
+        OptionValue::Number(v)
+    }
+}
+
 impl PartialEq<str> for OptionValue {
     fn eq(&self, other: &str) -> bool {
         match self {
@@ -35,13 +93,31 @@ impl PartialEq<OptionValue> for str {
 impl PartialEq<i32> for OptionValue {
     fn eq(&self, other: &i32) -> bool {
         match self {
-            OptionValue::Int(v) => v == other,
+            OptionValue::Int(v) => *v == i64::from(*other),
             _ => false,
         }
     }
 }
 
 impl PartialEq<OptionValue> for i32 {
+    fn eq(&self, other: &OptionValue) -> bool {
+        match other {
+            OptionValue::Int(v) => *v == i64::from(*self),
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<i64> for OptionValue {
+    fn eq(&self, other: &i64) -> bool {
+        match self {
+            OptionValue::Int(v) => v == other,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<OptionValue> for i64 {
     fn eq(&self, other: &OptionValue) -> bool {
         match other {
             OptionValue::Int(v) => v == self,
@@ -50,7 +126,91 @@ impl PartialEq<OptionValue> for i32 {
     }
 }
 
+impl PartialEq<bool> for OptionValue {
+    fn eq(&self, other: &bool) -> bool {
+        match self {
+            OptionValue::Bool(v) => v == other,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<OptionValue> for bool {
+    fn eq(&self, other: &OptionValue) -> bool {
+        match other {
+            OptionValue::Bool(v) => v == self,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<f64> for OptionValue {
+    fn eq(&self, other: &f64) -> bool {
+        match self {
+            OptionValue::Number(v) => v == other,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<OptionValue> for f64 {
+    fn eq(&self, other: &OptionValue) -> bool {
+        match other {
+            OptionValue::Number(v) => v == self,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<Snowflake> for OptionValue {
+    fn eq(&self, other: &Snowflake) -> bool {
+        self.try_as_snowflake() == Some(*other)
+    }
+}
+
+impl PartialEq<OptionValue> for Snowflake {
+    fn eq(&self, other: &OptionValue) -> bool {
+        other.try_as_snowflake() == Some(*self)
+    }
+}
+
 impl OptionValue {
+    pub(crate) fn from_raw<E: serde::de::Error>(
+        ty: ApplicationCommandOptionType,
+        raw: OptionValue,
+    ) -> Result<Self, E> {
+        fn snowflake<E: serde::de::Error>(raw: &OptionValue) -> Result<Snowflake, E> {
+            let id = raw
+                .try_as_str()
+                .ok_or_else(|| E::custom("expected a snowflake ID string"))?;
+            id.parse().map_err(|_| {
+                E::invalid_value(serde::de::Unexpected::Str(id), &"a snowflake ID string")
+            })
+        }
+
+        use ApplicationCommandOptionType as Type;
+
+        let value = match (ty, raw) {
+            (Type::String, v @ OptionValue::String(_)) => v,
+            (Type::Integer, v @ OptionValue::Int(_)) => v,
+            (Type::Boolean, v @ OptionValue::Bool(_)) => v,
+            (Type::User, raw) => OptionValue::User(snowflake(&raw)?),
+            (Type::Channel, raw) => OptionValue::Channel(snowflake(&raw)?),
+            (Type::Role, raw) => OptionValue::Role(snowflake(&raw)?),
+            (Type::Mentionable, raw) => OptionValue::Mentionable(snowflake(&raw)?),
+            (Type::Number, OptionValue::Int(v)) => OptionValue::Number(v as f64),
+            (Type::Number, v @ OptionValue::Number(_)) => v,
+            (Type::Attachment, raw) => OptionValue::Attachment(snowflake(&raw)?),
+            (ty, raw) => {
+                return Err(E::custom(format_args!(
+                    "value {} does not match option type {:?}",
+                    raw, ty
+                )))
+            }
+        };
+        Ok(value)
+    }
+
     pub fn try_into_string(self) -> Result<String, Self> {
         match self {
             OptionValue::String(v) => Ok(v),
@@ -58,18 +218,43 @@ impl OptionValue {
         }
     }
 
-    pub fn try_into_int(self) -> Result<i32, Self> {
+    pub fn try_into_int(self) -> Result<i64, Self> {
         match self {
             OptionValue::Int(v) => Ok(v),
             other => Err(other),
         }
     }
 
+    pub fn try_into_bool(self) -> Result<bool, Self> {
+        match self {
+            OptionValue::Bool(v) => Ok(v),
+            other => Err(other),
+        }
+    }
+
+    pub fn try_into_number(self) -> Result<f64, Self> {
+        match self {
+            OptionValue::Number(v) => Ok(v),
+            other => Err(other),
+        }
+    }
+
+    pub fn try_into_snowflake(self) -> Result<Snowflake, Self> {
+        match self.try_as_snowflake() {
+            Some(v) => Ok(v),
+            None => Err(self),
+        }
+    }
+
     pub fn into_string(self) -> String {
-        self.try_into_string().expect("given OptionValue is not String")
+        match self {
+            OptionValue::String(v) => v,
+            other => other.to_string(),
+        }
     }
 
-    pub fn into_int(self) -> i32 {
+    #[deprecated(note = "panics on non-Int values; use `try_into_int` instead")]
+    pub fn into_int(self) -> i64 {
         self.try_into_int().expect("given OptionValue is not Int")
     }
 
@@ -80,14 +265,77 @@ impl OptionValue {
         }
     }
 
-    pub fn try_as_int(&self) -> Option<i32> {
+    pub fn try_as_int(&self) -> Option<i64> {
         match self {
             OptionValue::Int(v) => Some(*v),
             _ => None,
         }
     }
 
-    pub fn as_str(&self) -> &str {
-        self.try_as_str().expect("given OptionValue is not String")
+    pub fn try_as_bool(&self) -> Option<bool> {
+        match self {
+            OptionValue::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn try_as_number(&self) -> Option<f64> {
+        match self {
+            OptionValue::Number(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn try_as_snowflake(&self) -> Option<Snowflake> {
+        match self {
+            OptionValue::User(v)
+            | OptionValue::Channel(v)
+            | OptionValue::Role(v)
+            | OptionValue::Mentionable(v)
+            | OptionValue::Attachment(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn try_as_user(&self) -> Option<Snowflake> {
+        match self {
+            OptionValue::User(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn try_as_channel(&self) -> Option<Snowflake> {
+        match self {
+            OptionValue::Channel(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn try_as_role(&self) -> Option<Snowflake> {
+        match self {
+            OptionValue::Role(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn try_as_mentionable(&self) -> Option<Snowflake> {
+        match self {
+            OptionValue::Mentionable(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn try_as_attachment(&self) -> Option<Snowflake> {
+        match self {
+            OptionValue::Attachment(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> std::borrow::Cow<'_, str> {
+        match self {
+            OptionValue::String(v) => std::borrow::Cow::Borrowed(v),
+            other => std::borrow::Cow::Owned(other.to_string()),
+        }
     }
 }
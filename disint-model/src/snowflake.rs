@@ -0,0 +1,142 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Snowflake(u64);
+
+impl Snowflake {
+    pub const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+    pub const fn new(id: u64) -> Self {
+        Self(id)
+    }
+
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// Creates the smallest snowflake for the given time, clamped to the range
+    /// a snowflake can represent.
+    pub fn from_timestamp(timestamp: chrono::DateTime<chrono::Utc>) -> Self {
+        let millis = timestamp
+            .timestamp_millis()
+            .saturating_sub(Self::DISCORD_EPOCH as i64)
+            .max(0) as u64;
+        Self(millis.min(u64::MAX >> 22) << 22)
+    }
+
+    pub const fn timestamp_millis(self) -> u64 {
+        (self.0 >> 22) + Self::DISCORD_EPOCH
+    }
+
+    pub fn timestamp(self) -> chrono::DateTime<chrono::Utc> {
+        use chrono::TimeZone;
+
+        chrono::Utc
+            .timestamp_millis_opt(self.timestamp_millis() as i64)
+            .unwrap()
+    }
+
+    pub const fn worker_id(self) -> u8 {
+        ((self.0 >> 17) & 0x1f) as u8
+    }
+
+    pub const fn process_id(self) -> u8 {
+        ((self.0 >> 12) & 0x1f) as u8
+    }
+
+    pub const fn increment(self) -> u16 {
+        (self.0 & 0xfff) as u16
+    }
+}
+
+impl From<u64> for Snowflake {
+    fn from(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+impl From<Snowflake> for u64 {
+    fn from(id: Snowflake) -> Self {
+        id.0
+    }
+}
+
+impl PartialEq<u64> for Snowflake {
+    fn eq(&self, other: &u64) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Snowflake> for u64 {
+    fn eq(&self, other: &Snowflake) -> bool {
+        *self == other.0
+    }
+}
+
+impl std::str::FromStr for Snowflake {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+
+impl fmt::Display for Snowflake {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl serde::Serialize for Snowflake {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        s.collect_str(&self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Snowflake {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SnowflakeVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SnowflakeVisitor {
+            type Value = Snowflake;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a snowflake ID string")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                v.parse()
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(Snowflake(v))
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                if v < 0 {
+                    Err(E::invalid_value(serde::de::Unexpected::Signed(v), &self))
+                } else {
+                    Ok(Snowflake(v as u64))
+                }
+            }
+        }
+
+        d.deserialize_any(SnowflakeVisitor)
+    }
+}
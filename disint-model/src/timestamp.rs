@@ -0,0 +1,37 @@
+use std::fmt;
+
+use chrono::{DateTime, Timelike, Utc};
+
+/// Formats timestamps the way Discord sends them: microsecond precision
+/// only when there are microseconds, and an explicit `+00:00` offset.
+struct DiscordTimestamp<'a>(&'a DateTime<Utc>);
+
+impl fmt::Display for DiscordTimestamp<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.nanosecond() / 1000 == 0 {
+            self.0.format("%Y-%m-%dT%H:%M:%S+00:00").fmt(f)
+        } else {
+            self.0.format("%Y-%m-%dT%H:%M:%S%.6f+00:00").fmt(f)
+        }
+    }
+}
+
+pub(crate) fn serialize<S>(timestamp: &DateTime<Utc>, s: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    s.collect_str(&DiscordTimestamp(timestamp))
+}
+
+pub(crate) fn serialize_option<S>(
+    timestamp: &Option<DateTime<Utc>>,
+    s: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match timestamp {
+        Some(timestamp) => serialize(timestamp, s),
+        None => s.serialize_none(),
+    }
+}
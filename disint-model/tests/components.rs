@@ -0,0 +1,89 @@
+use disint_model::interaction::components::{
+    ActionRow, Button, ButtonStyle, ComponentError, Components, ComponentsBuilder, TextInput,
+};
+use disint_model::{InteractionResponseBuilder, Snowflake};
+
+fn row(button: Button) -> Result<(), ComponentError> {
+    ComponentsBuilder::new()
+        .row(ActionRow::new().button(button))
+        .finish()
+        .map(drop)
+}
+
+#[test]
+fn button_styles() {
+    assert!(row(Button::primary("ok")).is_ok());
+    assert!(row(Button::link("https://example.com")).is_ok());
+    assert!(row(Button::premium(Snowflake::new(1))).is_ok());
+
+    for style in [ButtonStyle::Link, ButtonStyle::Premium] {
+        assert_eq!(
+            row(Button::new(style, "id")),
+            Err(ComponentError::InvalidButton { row: 0, style })
+        );
+    }
+}
+
+#[test]
+fn text_inputs_are_modal_only() {
+    let json = r#"[{"type": 1, "components": [{"type": 4, "custom_id": "name", "style": 1, "label": "Name"}]}]"#;
+    assert!(serde_json::from_str::<Components>(json).is_err());
+}
+
+#[test]
+fn deserialized_components_are_validated() {
+    let parse = |json: &str| serde_json::from_str::<Components>(json);
+    let button = |id: usize| {
+        format!(
+            r#"{{"type": 2, "style": 1, "custom_id": "b{}", "label": "B"}}"#,
+            id
+        )
+    };
+    let row = |components: &str| format!(r#"{{"type": 1, "components": [{}]}}"#, components);
+    let select = r#"{"type": 3, "custom_id": "s", "options": [{"label": "X", "value": "x"}]}"#;
+
+    let rows = |count: usize| {
+        let rows = (0..count).map(|idx| row(&button(idx))).collect::<Vec<_>>();
+        format!("[{}]", rows.join(","))
+    };
+    assert!(parse(&rows(5)).is_ok());
+    assert!(parse(&rows(6)).is_err());
+
+    let mixed = format!("[{}]", row(&format!("{},{}", button(0), select)));
+    assert!(parse(&format!("[{}]", row(select))).is_ok());
+    assert!(parse(&mixed).is_err());
+
+    let link =
+        r#"{"type": 2, "style": 5, "url": "https://example.com", "custom_id": "x", "label": "Go"}"#;
+    assert!(parse(&format!("[{}]", row(link))).is_err());
+    let link = link.replace(r#", "custom_id": "x""#, "");
+    assert!(parse(&format!("[{}]", row(&link))).is_ok());
+}
+
+#[test]
+fn modal_limits() {
+    let modal = |custom_id: &str, inputs: usize, input_id: &str| {
+        let mut builder = InteractionResponseBuilder::modal(custom_id, "Title")
+            .text_input(TextInput::short(input_id, "Label"));
+        for idx in 1..inputs {
+            builder = builder.text_input(TextInput::short(format!("input-{}", idx), "Label"));
+        }
+        builder.finish()
+    };
+
+    assert!(modal("modal", 5, "input").is_ok());
+    assert_eq!(
+        modal("modal", 6, "input").unwrap_err(),
+        ComponentError::TooManyRows { count: 6 }
+    );
+
+    let long = "x".repeat(101);
+    assert!(matches!(
+        modal(&long, 1, "input").unwrap_err(),
+        ComponentError::CustomIdTooLong { custom_id } if custom_id == long
+    ));
+    assert!(matches!(
+        modal("modal", 1, &long).unwrap_err(),
+        ComponentError::CustomIdTooLong { custom_id } if custom_id == long
+    ));
+}
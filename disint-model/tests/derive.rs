@@ -0,0 +1,280 @@
+use disint_model::command::{CommandChoice, CommandDefinition, OptionKind};
+use disint_model::interaction::{ApplicationCommandInteractionData, OptionError};
+use disint_model::{CommandOptions, OptionValue};
+use serde_json::json;
+
+/// Show a picture of an animal
+#[derive(Debug, PartialEq, disint_model::CommandDefinition)]
+#[command(name = "blep")]
+enum Blep {
+    /// Pick an animal
+    Animal {
+        /// Size of the picture
+        size: Option<Size>,
+        /// Whether to show only small animals
+        only_smol: bool,
+        /// The type of animal
+        #[option(rename = "kind")]
+        animal: Animal,
+        /// How many pictures
+        #[option(min_value = 1, max_value = 5)]
+        count: Option<i64>,
+        /// Caption for the picture
+        #[option(max_length = 100, autocomplete)]
+        caption: String,
+    },
+    /// Show a random animal
+    #[command(rename = "random")]
+    Surprise,
+}
+
+#[derive(Debug, PartialEq, disint_model::CommandChoice)]
+enum Animal {
+    #[choice(localize("ko", "개"))]
+    Dog,
+    #[choice(name = "Penguin", value = "animal_penguin")]
+    Penguin,
+    GuineaPig,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, disint_model::CommandChoice)]
+#[choice(int)]
+enum Size {
+    Small = 1,
+    #[choice(localize("ko", "큰"))]
+    Large = 3,
+}
+
+#[derive(Debug, PartialEq, disint_model::CommandOptions)]
+struct Tag {
+    #[option(rename = "tag-name")]
+    name: String,
+    r#ref: Option<i64>,
+    size: Option<Size>,
+}
+
+fn data(value: serde_json::Value) -> ApplicationCommandInteractionData {
+    serde_json::from_value(value).unwrap()
+}
+
+fn blep(options: serde_json::Value) -> ApplicationCommandInteractionData {
+    data(json!({
+        "id": "771825006014889984",
+        "name": "blep",
+        "type": 1,
+        "options": [options],
+    }))
+}
+
+#[test]
+fn definition() {
+    let definition = serde_json::to_value(Blep::definition()).unwrap();
+    let expected = json!({
+        "name": "blep",
+        "description": "Show a picture of an animal",
+        "type": 1,
+        "options": [
+            {
+                "name": "animal",
+                "description": "Pick an animal",
+                "type": 1,
+                "options": [
+                    {
+                        "name": "only_smol",
+                        "description": "Whether to show only small animals",
+                        "type": 5,
+                        "required": true
+                    },
+                    {
+                        "name": "kind",
+                        "description": "The type of animal",
+                        "type": 3,
+                        "required": true,
+                        "choices": [
+                            { "name": "Dog", "name_localizations": { "ko": "개" }, "value": "dog" },
+                            { "name": "Penguin", "value": "animal_penguin" },
+                            { "name": "GuineaPig", "value": "guinea-pig" }
+                        ]
+                    },
+                    {
+                        "name": "caption",
+                        "description": "Caption for the picture",
+                        "type": 3,
+                        "required": true,
+                        "max_length": 100,
+                        "autocomplete": true
+                    },
+                    {
+                        "name": "size",
+                        "description": "Size of the picture",
+                        "type": 4,
+                        "required": false,
+                        "choices": [
+                            { "name": "Small", "value": 1 },
+                            { "name": "Large", "name_localizations": { "ko": "큰" }, "value": 3 }
+                        ]
+                    },
+                    {
+                        "name": "count",
+                        "description": "How many pictures",
+                        "type": 4,
+                        "required": false,
+                        "min_value": 1,
+                        "max_value": 5
+                    }
+                ]
+            },
+            {
+                "name": "random",
+                "description": "Show a random animal",
+                "type": 1
+            }
+        ]
+    });
+    assert_eq!(definition, expected);
+}
+
+#[test]
+fn parse_subcommand() {
+    let parsed = Blep::from_data(&blep(json!({
+        "name": "animal",
+        "type": 1,
+        "options": [
+            { "name": "kind", "type": 3, "value": "animal_penguin" },
+            { "name": "only_smol", "type": 5, "value": true },
+            { "name": "caption", "type": 3, "value": "noot" },
+            { "name": "size", "type": 4, "value": 3 }
+        ]
+    })))
+    .unwrap();
+    assert_eq!(
+        parsed,
+        Blep::Animal {
+            size: Some(Size::Large),
+            only_smol: true,
+            animal: Animal::Penguin,
+            count: None,
+            caption: "noot".to_owned(),
+        }
+    );
+}
+
+#[test]
+fn parse_unit_subcommand() {
+    let parsed = Blep::from_data(&blep(json!({ "name": "random", "type": 1 }))).unwrap();
+    assert_eq!(parsed, Blep::Surprise);
+}
+
+#[test]
+fn unknown_subcommand() {
+    let err = Blep::from_data(&blep(json!({ "name": "surprise", "type": 1 }))).unwrap_err();
+    assert_eq!(
+        err,
+        OptionError::UnknownSubcommand {
+            name: "surprise".to_owned()
+        }
+    );
+
+    let err = Blep::from_data(&data(json!({
+        "id": "771825006014889984",
+        "name": "blep",
+        "type": 1
+    })))
+    .unwrap_err();
+    assert_eq!(
+        err,
+        OptionError::UnknownSubcommand {
+            name: String::new()
+        }
+    );
+    assert_eq!(err.to_string(), "missing subcommand");
+}
+
+#[test]
+fn unknown_choice() {
+    let err = Blep::from_data(&blep(json!({
+        "name": "animal",
+        "type": 1,
+        "options": [
+            { "name": "kind", "type": 3, "value": "cat" },
+            { "name": "only_smol", "type": 5, "value": false },
+            { "name": "caption", "type": 3, "value": "" }
+        ]
+    })))
+    .unwrap_err();
+    assert_eq!(
+        err,
+        OptionError::UnknownChoice {
+            name: "kind".to_owned(),
+            value: "cat".to_owned(),
+        }
+    );
+}
+
+#[test]
+fn missing_required_option() {
+    let err = Blep::from_data(&blep(json!({
+        "name": "animal",
+        "type": 1,
+        "options": [
+            { "name": "kind", "type": 3, "value": "dog" },
+            { "name": "caption", "type": 3, "value": "" }
+        ]
+    })))
+    .unwrap_err();
+    assert!(err.is_missing());
+    assert_eq!(err.name(), "only_smol");
+}
+
+#[test]
+fn choice_values() {
+    assert_eq!(
+        Animal::GuineaPig.to_value(),
+        OptionValue::from("guinea-pig")
+    );
+    assert_eq!(
+        Animal::from_value(&OptionValue::from("dog")),
+        Some(Animal::Dog)
+    );
+    assert_eq!(Animal::from_value(&OptionValue::from(1i64)), None);
+
+    assert_eq!(Size::Large.to_value(), OptionValue::from(3i64));
+    assert_eq!(
+        Size::from_value(&OptionValue::from(1i64)),
+        Some(Size::Small)
+    );
+    assert_eq!(Size::from_value(&OptionValue::from(2i64)), None);
+
+    assert_eq!(<Option<Size> as OptionKind>::choices().len(), 2);
+}
+
+#[test]
+fn options_struct() {
+    let parsed = Tag::from_data(&data(json!({
+        "id": "771825006014889987",
+        "name": "tag",
+        "type": 1,
+        "options": [
+            { "name": "tag-name", "type": 3, "value": "rust" },
+            { "name": "ref", "type": 4, "value": 7 }
+        ]
+    })))
+    .unwrap();
+    assert_eq!(
+        parsed,
+        Tag {
+            name: "rust".to_owned(),
+            r#ref: Some(7),
+            size: None,
+        }
+    );
+
+    let err = Tag::from_data(&data(json!({
+        "id": "771825006014889987",
+        "name": "tag",
+        "type": 1,
+        "options": [{ "name": "tag-name", "type": 4, "value": 1 }]
+    })))
+    .unwrap_err();
+    assert!(matches!(err, OptionError::WrongType { ref name, .. } if name == "tag-name"));
+}
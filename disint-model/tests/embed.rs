@@ -0,0 +1,249 @@
+use disint_model::interaction::embed::{
+    validate_embeds, Author, Embed, EmbedError, EmbedType, Field, Footer,
+};
+use disint_model::InteractionResponseBuilder;
+
+fn text(ch: char, count: usize) -> String {
+    ch.to_string().repeat(count)
+}
+
+fn description(count: usize) -> Embed {
+    Embed::builder()
+        .description(text('a', count))
+        .finish()
+        .unwrap()
+}
+
+#[test]
+fn text_limits() {
+    assert!(Embed::builder().title(text('a', 256)).finish().is_ok());
+    assert_eq!(
+        Embed::builder().title(text('a', 257)).finish().unwrap_err(),
+        EmbedError::TooLong {
+            part: "title",
+            length: 257,
+            max: 256
+        }
+    );
+    assert_eq!(
+        Embed::builder()
+            .description(text('a', 4097))
+            .finish()
+            .unwrap_err(),
+        EmbedError::TooLong {
+            part: "description",
+            length: 4097,
+            max: 4096
+        }
+    );
+
+    let footer = Footer {
+        text: text('a', 2049),
+        ..Footer::default()
+    };
+    assert_eq!(
+        Embed::builder().footer(footer).finish().unwrap_err(),
+        EmbedError::TooLong {
+            part: "footer text",
+            length: 2049,
+            max: 2048
+        }
+    );
+
+    let author = Author {
+        name: Some(text('a', 257)),
+        ..Author::default()
+    };
+    assert_eq!(
+        Embed::builder().author(author).finish().unwrap_err(),
+        EmbedError::TooLong {
+            part: "author name",
+            length: 257,
+            max: 256
+        }
+    );
+}
+
+#[test]
+fn lengths_count_characters() {
+    // 256 characters, but 768 bytes
+    assert!(Embed::builder().title(text('가', 256)).finish().is_ok());
+    assert_eq!(
+        Embed::builder()
+            .title(text('가', 257))
+            .finish()
+            .unwrap_err(),
+        EmbedError::TooLong {
+            part: "title",
+            length: 257,
+            max: 256
+        }
+    );
+}
+
+#[test]
+fn field_limits() {
+    let embed = Embed::builder()
+        .field(Field::new("ok", "ok"))
+        .field(Field::new(text('a', 257), "value"))
+        .finish();
+    assert_eq!(
+        embed.unwrap_err(),
+        EmbedError::FieldTooLong {
+            index: 1,
+            part: "name",
+            length: 257,
+            max: 256
+        }
+    );
+
+    let embed = Embed::builder()
+        .field(Field::new("name", text('a', 1025)))
+        .finish();
+    assert_eq!(
+        embed.unwrap_err(),
+        EmbedError::FieldTooLong {
+            index: 0,
+            part: "value",
+            length: 1025,
+            max: 1024
+        }
+    );
+
+    let fields = (0..26).map(|idx| Field::new(idx.to_string(), "value"));
+    assert!(Embed::builder()
+        .fields(fields.clone().take(25))
+        .finish()
+        .is_ok());
+    assert_eq!(
+        Embed::builder().fields(fields).finish().unwrap_err(),
+        EmbedError::TooManyFields { count: 26 }
+    );
+}
+
+#[test]
+fn total_length() {
+    let embed = Embed::builder()
+        .description(text('a', 4096))
+        .field(Field::new("a", text('a', 1024)))
+        .field(Field::new("b", text('a', 1024)))
+        .finish();
+    assert_eq!(
+        embed.unwrap_err(),
+        EmbedError::TotalTooLong { length: 6146 }
+    );
+
+    assert!(validate_embeds(&[description(3000), description(3000)]).is_ok());
+    assert_eq!(
+        validate_embeds(&[description(3000), description(3001)]),
+        Err(EmbedError::TotalTooLong { length: 6001 })
+    );
+}
+
+#[test]
+fn embed_count() {
+    let embeds = vec![description(10); 11];
+    assert!(validate_embeds(&embeds[..10]).is_ok());
+    assert_eq!(
+        validate_embeds(&embeds),
+        Err(EmbedError::TooManyEmbeds { count: 11 })
+    );
+}
+
+#[test]
+fn message_builders_check_embeds() {
+    let embeds = vec![description(10); 11];
+
+    let mut message = InteractionResponseBuilder::channel_message().content("hi");
+    for embed in &embeds {
+        message = message.embed(embed.clone());
+    }
+    assert_eq!(
+        message.finish().unwrap_err(),
+        EmbedError::TooManyEmbeds { count: 11 }
+    );
+
+    let mut update = InteractionResponseBuilder::update_message();
+    for embed in &embeds {
+        update = update.embed(embed.clone());
+    }
+    assert_eq!(
+        update.finish().unwrap_err(),
+        EmbedError::TooManyEmbeds { count: 11 }
+    );
+
+    let response = InteractionResponseBuilder::channel_message()
+        .embed(description(3000))
+        .embed(description(3001))
+        .finish();
+    assert_eq!(
+        response.unwrap_err(),
+        EmbedError::TotalTooLong { length: 6001 }
+    );
+
+    let response = InteractionResponseBuilder::channel_message()
+        .embed(description(10))
+        .finish()
+        .unwrap();
+    assert_eq!(response.embeds().len(), 1);
+}
+
+#[test]
+fn truncate() {
+    let embed = Embed::builder()
+        .title(text('가', 300))
+        .description("short")
+        .field(Field::new(text('🐶', 300), text('é', 2000)))
+        .truncate()
+        .finish()
+        .unwrap();
+
+    let title = embed.title.as_deref().unwrap();
+    assert_eq!(title.chars().count(), 256);
+    assert!(title.ends_with("가…"));
+    assert_eq!(embed.description.as_deref(), Some("short"));
+
+    let field = &embed.fields.as_ref().unwrap()[0];
+    assert_eq!(field.name().chars().count(), 256);
+    assert!(field.name().ends_with("🐶…"));
+    assert_eq!(field.value().chars().count(), 1024);
+    assert!(field.value().ends_with("é…"));
+}
+
+#[test]
+fn truncate_keeps_count_and_total_checks() {
+    let fields = (0..26).map(|idx| Field::new(idx.to_string(), "value"));
+    assert_eq!(
+        Embed::builder()
+            .fields(fields)
+            .truncate()
+            .finish()
+            .unwrap_err(),
+        EmbedError::TooManyFields { count: 26 }
+    );
+
+    let embed = Embed::builder()
+        .description(text('a', 5000))
+        .fields((0..2).map(|idx| Field::new(idx.to_string(), text('a', 2000))))
+        .truncate()
+        .finish();
+    assert_eq!(
+        embed.unwrap_err(),
+        EmbedError::TotalTooLong { length: 6146 }
+    );
+}
+
+#[test]
+fn unknown_embed_type() {
+    let json = r#"{"type":"auto_moderation_message"}"#;
+    let embed: Embed = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        embed.ty,
+        Some(EmbedType::Unknown("auto_moderation_message".into()))
+    );
+    assert_eq!(serde_json::to_string(&embed).unwrap(), json);
+
+    let embed: Embed = serde_json::from_str(r#"{"type": "gifv"}"#).unwrap();
+    assert_eq!(embed.ty, Some(EmbedType::Gifv));
+    assert_eq!(serde_json::to_string(&embed).unwrap(), r#"{"type":"gifv"}"#);
+}
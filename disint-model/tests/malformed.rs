@@ -0,0 +1,76 @@
+use disint_model::Interaction;
+use serde_json::{json, Value};
+
+fn interaction() -> Value {
+    json!({
+        "version": 1,
+        "id": "1290373423404204043",
+        "token": "token",
+        "type": 2,
+        "guild_id": "290926798626357250",
+        "channel_id": "645027906669510667",
+        "member": {
+            "user": {
+                "id": "53908232506183680",
+                "username": "mason",
+                "discriminator": "0",
+                "avatar": null
+            },
+            "nick": null,
+            "roles": ["290926798999357250"],
+            "joined_at": "2017-03-13T19:19:14.040000+00:00",
+            "premium_since": null,
+            "deaf": false,
+            "mute": false
+        },
+        "data": {
+            "id": "771825006014889990",
+            "name": "dice",
+            "type": 1,
+            "options": [
+                {"name": "sides", "type": 4, "value": 6},
+                {"name": "target", "type": 6, "value": "53908232506183680"}
+            ]
+        }
+    })
+}
+
+fn parse_with(pointer: &str, value: Value) -> serde_json::Result<Interaction> {
+    let mut raw = interaction();
+    *raw.pointer_mut(pointer).unwrap() = value;
+    serde_json::from_value(raw)
+}
+
+#[test]
+fn well_formed() {
+    assert!(serde_json::from_value::<Interaction>(interaction()).is_ok());
+}
+
+#[test]
+fn snowflakes() {
+    assert!(parse_with("/id", json!("not a snowflake")).is_err());
+    assert!(parse_with("/id", json!(-1)).is_err());
+    assert!(parse_with("/member/user/id", json!("mason")).is_err());
+    assert!(parse_with("/member/roles/0", json!("admin")).is_err());
+    assert!(parse_with("/guild_id", json!("")).is_err());
+}
+
+#[test]
+fn discriminators() {
+    assert!(parse_with("/member/user/discriminator", json!("1337")).is_ok());
+
+    for discriminator in &["", "12345", "13a7", "-1"] {
+        let err = parse_with("/member/user/discriminator", json!(discriminator)).unwrap_err();
+        assert!(err.to_string().contains("discriminator"), "{}", err);
+    }
+    assert!(parse_with("/member/user/discriminator", json!(1337)).is_err());
+}
+
+#[test]
+fn option_values_match_their_type() {
+    assert!(parse_with("/data/options/0/value", json!("six")).is_err());
+    assert!(parse_with("/data/options/0/value", json!(true)).is_err());
+    assert!(parse_with("/data/options/1/value", json!("mason")).is_err());
+    assert!(parse_with("/data/options/1/value", json!(53908232506183680u64)).is_err());
+    assert!(parse_with("/data/options/0", json!({"name": "sides", "type": 4})).is_err());
+}
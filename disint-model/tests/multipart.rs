@@ -0,0 +1,123 @@
+use disint_model::interaction::response::File;
+use disint_model::InteractionResponseBuilder;
+
+struct Part<'a> {
+    headers: &'a str,
+    body: &'a [u8],
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn split_parts<'a>(body: &'a [u8], boundary: &str) -> Vec<Part<'a>> {
+    let delimiter = format!("--{}", boundary);
+    let close = format!("--{}--\r\n", boundary);
+    assert!(body.starts_with(delimiter.as_bytes()));
+    assert!(body.ends_with(close.as_bytes()));
+
+    let mut parts = Vec::new();
+    let mut rest = &body[delimiter.len()..];
+    while rest.starts_with(b"\r\n") {
+        rest = &rest[2..];
+        let header_end = find(rest, b"\r\n\r\n").unwrap();
+        let headers = std::str::from_utf8(&rest[..header_end]).unwrap();
+        rest = &rest[header_end + 4..];
+
+        let next = format!("\r\n{}", delimiter);
+        let body_end = find(rest, next.as_bytes()).unwrap();
+        parts.push(Part {
+            headers,
+            body: &rest[..body_end],
+        });
+        rest = &rest[body_end + next.len()..];
+    }
+    assert_eq!(rest, b"--\r\n");
+    parts
+}
+
+#[test]
+fn files_follow_payload() {
+    let response = InteractionResponseBuilder::channel_message()
+        .content("Here you go")
+        .file(File::new("dog.png", &b"\x89PNG\r\n\x1a\n"[..]).content_type("image/png"))
+        .file(File::new("notes \"v2\".txt", "woof").description("Notes"))
+        .finish()
+        .unwrap();
+    let multipart = response.to_multipart().unwrap();
+
+    let boundary = multipart.boundary();
+    assert_eq!(
+        multipart.content_type(),
+        format!("multipart/form-data; boundary={}", boundary)
+    );
+
+    let parts = split_parts(multipart.body(), boundary);
+    assert_eq!(parts.len(), 3);
+
+    let payload = &parts[0];
+    assert_eq!(
+        payload.headers,
+        "Content-Disposition: form-data; name=\"payload_json\"\r\nContent-Type: application/json"
+    );
+    let payload: serde_json::Value = serde_json::from_slice(payload.body).unwrap();
+    assert_eq!(payload["type"], 4);
+    let attachments = payload["data"]["attachments"].as_array().unwrap();
+    assert_eq!(attachments.len(), 2);
+
+    let expected = [
+        ("dog.png", "dog.png", "image/png", &b"\x89PNG\r\n\x1a\n"[..]),
+        (
+            "notes \"v2\".txt",
+            "notes %22v2%22.txt",
+            "application/octet-stream",
+            &b"woof"[..],
+        ),
+    ];
+    for (idx, (part, (filename, escaped, content_type, data))) in
+        parts[1..].iter().zip(&expected).enumerate()
+    {
+        assert_eq!(
+            part.headers,
+            format!(
+                "Content-Disposition: form-data; name=\"files[{}]\"; filename=\"{}\"\r\nContent-Type: {}",
+                idx, escaped, content_type
+            )
+        );
+        assert_eq!(part.body, *data);
+
+        let attachment = &attachments[idx];
+        assert_eq!(attachment["id"], idx);
+        assert_eq!(attachment["filename"], *filename);
+    }
+    assert_eq!(attachments[1]["description"], "Notes");
+}
+
+#[test]
+fn payload_only() {
+    let response = InteractionResponseBuilder::channel_message()
+        .content("No files")
+        .finish()
+        .unwrap();
+    let multipart = response.to_multipart().unwrap();
+    let parts = split_parts(multipart.body(), multipart.boundary());
+    assert_eq!(parts.len(), 1);
+
+    let payload: serde_json::Value = serde_json::from_slice(parts[0].body).unwrap();
+    assert_eq!(payload, serde_json::to_value(&response).unwrap());
+    assert!(payload["data"].get("attachments").is_none());
+}
+
+#[test]
+fn boundary_is_not_in_content() {
+    let response = InteractionResponseBuilder::channel_message()
+        .file(File::new("a.bin", vec![b'-'; 64]))
+        .finish()
+        .unwrap();
+    let multipart = response.to_multipart().unwrap();
+    let boundary = multipart.boundary().as_bytes();
+    assert_eq!(find(response.files()[0].data(), boundary), None);
+    assert_eq!(split_parts(multipart.body(), multipart.boundary()).len(), 2);
+}
@@ -0,0 +1,72 @@
+use disint_model::interaction::{
+    ApplicationCommandInteractionData, ApplicationCommandInteractionDataOption,
+    ApplicationCommandOptionType, InteractionTypeAndData,
+};
+use disint_model::{Interaction, OptionValue};
+
+fn command_data(interaction: &Interaction) -> &ApplicationCommandInteractionData {
+    match interaction.data() {
+        InteractionTypeAndData::ApplicationCommand { data, .. }
+        | InteractionTypeAndData::ApplicationCommandAutocomplete { data, .. } => data,
+        _ => panic!("expected an application command"),
+    }
+}
+
+#[test]
+fn focused_partial_input() {
+    let interaction: Interaction =
+        serde_json::from_str(include_str!("fixtures/autocomplete_partial.json")).unwrap();
+    let data = command_data(&interaction);
+
+    let focused = data.focused_option().unwrap();
+    assert_eq!(focused.path(), ["roll"]);
+    assert_eq!(focused.name(), "count");
+    assert_eq!(focused.partial_input(), "1d");
+
+    // The focused value stays as typed, the rest are still checked against their type.
+    let leaves = data.leaf_options();
+    assert_eq!(leaves[0].value().and_then(OptionValue::try_as_int), Some(6));
+    assert_eq!(
+        leaves[1].option_type(),
+        ApplicationCommandOptionType::Integer
+    );
+    assert_eq!(
+        leaves[1].value().and_then(OptionValue::try_as_str),
+        Some("1d")
+    );
+}
+
+#[test]
+fn subcommand_group() {
+    let interaction: Interaction =
+        serde_json::from_str(include_str!("fixtures/subcommand_group.json")).unwrap();
+    let data = command_data(&interaction);
+
+    let group = &data.options[0];
+    assert!(matches!(
+        group,
+        ApplicationCommandInteractionDataOption::SubcommandGroup { name, .. } if name == "group"
+    ));
+    assert_eq!(
+        group.option_type(),
+        ApplicationCommandOptionType::SubCommandGroup
+    );
+
+    let sub = &group.options()[0];
+    assert!(matches!(
+        sub,
+        ApplicationCommandInteractionDataOption::Subcommand { name, .. } if name == "sub"
+    ));
+    assert_eq!(sub.option_type(), ApplicationCommandOptionType::SubCommand);
+
+    let role = &sub.options()[0];
+    assert!(matches!(
+        role,
+        ApplicationCommandInteractionDataOption::Value { name, .. } if name == "role"
+    ));
+    assert_eq!(role.option_type(), ApplicationCommandOptionType::Role);
+
+    assert_eq!(data.subcommand_path(), ["group", "sub"]);
+    assert!(data.is_subcommand(&["group", "sub"]));
+    assert_eq!(data.leaf_options().len(), 1);
+}
@@ -0,0 +1,48 @@
+use disint_model::Permissions;
+
+#[test]
+fn string_round_trip() {
+    let permissions: Permissions = serde_json::from_str(r#""2147483647""#).unwrap();
+    assert!(permissions.contains(Permissions::ADMINISTRATOR | Permissions::SEND_MESSAGES));
+    assert_eq!(
+        serde_json::to_string(&permissions).unwrap(),
+        r#""2147483647""#
+    );
+
+    let newer = Permissions::SET_VOICE_CHANNEL_STATUS
+        | Permissions::PIN_MESSAGES
+        | Permissions::BYPASS_SLOWMODE;
+    let json = serde_json::to_string(&newer).unwrap();
+    assert_eq!(
+        json,
+        format!(r#""{}""#, (1u64 << 48) | (1 << 51) | (1 << 52))
+    );
+    assert_eq!(serde_json::from_str::<Permissions>(&json).unwrap(), newer);
+}
+
+#[test]
+fn unknown_bits_are_kept() {
+    let bits = (1u64 << 63) | 1;
+    let permissions: Permissions = bits.to_string().parse().unwrap();
+    assert_eq!(permissions.bits(), bits);
+    assert_eq!(permissions.to_string(), bits.to_string());
+}
+
+#[test]
+fn invalid_strings() {
+    assert!(serde_json::from_str::<Permissions>(r#""-1""#).is_err());
+    assert!(serde_json::from_str::<Permissions>(r#""admin""#).is_err());
+}
+
+#[test]
+fn missing() {
+    let granted = Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS;
+    let required =
+        Permissions::SEND_MESSAGES | Permissions::ATTACH_FILES | Permissions::PIN_MESSAGES;
+    assert_eq!(
+        granted.missing(required),
+        Permissions::ATTACH_FILES | Permissions::PIN_MESSAGES
+    );
+    assert!(granted.missing(Permissions::SEND_MESSAGES).is_empty());
+    assert!(granted.missing(Permissions::empty()).is_empty());
+}
@@ -0,0 +1,285 @@
+use std::collections::HashSet;
+
+use disint_model::command::ApplicationCommand;
+use disint_model::interaction::response::InteractionResponse;
+use disint_model::interaction::{ApplicationCommandType, InteractionTypeAndData};
+use disint_model::{Color, Interaction};
+use serde::de::{DeserializeOwned, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Walks a JSON document and fails on repeated object keys, which `Value`
+/// silently collapses.
+struct NoDuplicateKeys;
+
+impl<'de> Deserialize<'de> for NoDuplicateKeys {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        d.deserialize_any(NoDuplicateKeysVisitor)
+    }
+}
+
+struct NoDuplicateKeysVisitor;
+
+impl<'de> Visitor<'de> for NoDuplicateKeysVisitor {
+    type Value = NoDuplicateKeys;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("any JSON value")
+    }
+
+    fn visit_bool<E>(self, _: bool) -> Result<Self::Value, E> {
+        Ok(NoDuplicateKeys)
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<Self::Value, E> {
+        Ok(NoDuplicateKeys)
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<Self::Value, E> {
+        Ok(NoDuplicateKeys)
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<Self::Value, E> {
+        Ok(NoDuplicateKeys)
+    }
+
+    fn visit_str<E>(self, _: &str) -> Result<Self::Value, E> {
+        Ok(NoDuplicateKeys)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(NoDuplicateKeys)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        while seq.next_element::<NoDuplicateKeys>()?.is_some() {}
+        Ok(NoDuplicateKeys)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut keys = HashSet::new();
+        while let Some(key) = map.next_key::<String>()? {
+            if !keys.insert(key.clone()) {
+                return Err(serde::de::Error::custom(format!("duplicate key `{}`", key)));
+            }
+            map.next_value::<NoDuplicateKeys>()?;
+        }
+        Ok(NoDuplicateKeys)
+    }
+}
+
+fn round_trip<T>(fixture: &str) -> T
+where
+    T: Serialize + DeserializeOwned,
+{
+    let expected: Value = serde_json::from_str(fixture).unwrap();
+    let parsed: T = serde_json::from_str(fixture).unwrap();
+    let serialized_str = serde_json::to_string(&parsed).unwrap();
+    serde_json::from_str::<NoDuplicateKeys>(&serialized_str).unwrap();
+    let serialized: Value = serde_json::from_str(&serialized_str).unwrap();
+    assert_eq!(serialized, expected);
+
+    let reparsed: T = serde_json::from_value(serialized.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&reparsed).unwrap(), serialized);
+    parsed
+}
+
+#[test]
+fn ping() {
+    let interaction: Interaction = round_trip(include_str!("fixtures/ping.json"));
+    assert!(matches!(interaction.data(), InteractionTypeAndData::Ping));
+}
+
+#[test]
+fn slash_command_in_guild() {
+    let interaction: Interaction = round_trip(include_str!("fixtures/slash_command_guild.json"));
+    assert!(matches!(
+        interaction.data(),
+        InteractionTypeAndData::ApplicationCommand { .. }
+    ));
+    assert_eq!(
+        interaction.invoking_user().unwrap().id().to_string(),
+        "53908232506183680"
+    );
+
+    if let InteractionTypeAndData::ApplicationCommand { data, .. } = interaction.data() {
+        let role = data.resolved.roles().next().unwrap();
+        assert_eq!(role.color(), Color::new(0x3498db));
+    }
+}
+
+#[test]
+fn slash_command_in_dm() {
+    let interaction: Interaction = round_trip(include_str!("fixtures/slash_command_dm.json"));
+    assert!(!interaction.source().unwrap().is_guild());
+    assert_eq!(
+        interaction.invoking_user().unwrap().id().to_string(),
+        "53908232506183680"
+    );
+}
+
+#[test]
+fn newer_command_types() {
+    let interaction = |ty: u8| {
+        format!(
+            r#"{{
+                "version": 1,
+                "id": "1290373423404204040",
+                "token": "token",
+                "type": 2,
+                "channel_id": "645027906669510667",
+                "user": {{"id": "53908232506183680", "username": "mason", "discriminator": "0", "avatar": null}},
+                "data": {{"id": "771825006014889987", "name": "launch", "type": {}}}
+            }}"#,
+            ty
+        )
+    };
+
+    for (ty, expected) in [
+        (4, ApplicationCommandType::PrimaryEntryPoint),
+        (42, ApplicationCommandType::Unknown(42)),
+    ] {
+        let interaction: Interaction = round_trip(&interaction(ty));
+        match interaction.data() {
+            InteractionTypeAndData::ApplicationCommand { data, .. } => {
+                assert_eq!(data.ty, expected);
+                assert!(data.target().is_none());
+            }
+            _ => panic!("expected an application command"),
+        }
+
+        let command: ApplicationCommand = round_trip(&format!(
+            r#"{{"id": "771825006014889987", "type": {}, "name": "launch", "description": ""}}"#,
+            ty
+        ));
+        assert_eq!(command.command_type(), expected);
+    }
+}
+
+#[test]
+fn user_command() {
+    round_trip::<Interaction>(include_str!("fixtures/user_command.json"));
+}
+
+#[test]
+fn autocomplete() {
+    let interaction: Interaction = round_trip(include_str!("fixtures/autocomplete.json"));
+    assert!(matches!(
+        interaction.data(),
+        InteractionTypeAndData::ApplicationCommandAutocomplete { .. }
+    ));
+}
+
+#[test]
+fn message_component() {
+    let interaction: Interaction = round_trip(include_str!("fixtures/message_component.json"));
+    assert!(matches!(
+        interaction.data(),
+        InteractionTypeAndData::MessageComponent { .. }
+    ));
+}
+
+#[test]
+fn message_component_minimal_message() {
+    let interaction: Interaction = round_trip(
+        r#"{
+            "version": 1,
+            "id": "1290373423404204039",
+            "token": "token",
+            "type": 3,
+            "channel_id": "645027906669510667",
+            "user": {"id": "53908232506183680", "username": "mason", "discriminator": "0", "avatar": null},
+            "message": {
+                "id": "1290373312036732960",
+                "channel_id": "645027906669510667",
+                "author": {"id": "771824997362286602", "username": "blep-bot", "discriminator": "0", "avatar": null},
+                "content": "Which class?",
+                "timestamp": "2024-09-30T15:42:01.513000+00:00",
+                "edited_timestamp": null
+            },
+            "data": {"custom_id": "ok", "component_type": 2}
+        }"#,
+    );
+    if let InteractionTypeAndData::MessageComponent { message, .. } = interaction.data() {
+        assert!(!message.is_pinned());
+        assert!(message.flags().is_empty());
+    }
+}
+
+#[test]
+fn modal_submit() {
+    let interaction: Interaction = round_trip(include_str!("fixtures/modal_submit.json"));
+    assert!(matches!(
+        interaction.data(),
+        InteractionTypeAndData::ModalSubmit { message: None, .. }
+    ));
+}
+
+#[test]
+fn unknown_interaction() {
+    let interaction: Interaction = round_trip(include_str!("fixtures/unknown.json"));
+    assert!(matches!(
+        interaction.data(),
+        InteractionTypeAndData::Unknown { ty: 42, .. }
+    ));
+}
+
+#[test]
+fn channel_message_response() {
+    let response: InteractionResponse =
+        round_trip(include_str!("fixtures/response_channel_message.json"));
+    assert_eq!(response.files().len(), 1);
+    assert_eq!(response.files()[0].filename(), "dog.png");
+}
+
+#[test]
+fn deferred_response() {
+    round_trip::<InteractionResponse>(include_str!("fixtures/response_deferred.json"));
+
+    let response: InteractionResponse = round_trip(r#"{"type": 5, "data": null}"#);
+    assert!(matches!(
+        response,
+        InteractionResponse::DeferredChannelMessageWithSource(flags) if flags.is_empty()
+    ));
+}
+
+#[test]
+fn autocomplete_response() {
+    round_trip::<InteractionResponse>(include_str!("fixtures/response_autocomplete.json"));
+}
+
+#[test]
+fn modal_response() {
+    round_trip::<InteractionResponse>(include_str!("fixtures/response_modal.json"));
+}
+
+#[test]
+fn update_message_response() {
+    round_trip::<InteractionResponse>(include_str!("fixtures/response_update.json"));
+}
+
+#[test]
+fn payloadless_responses() {
+    for ty in &[1, 6, 12] {
+        round_trip::<InteractionResponse>(&format!(r#"{{"type": {}, "data": null}}"#, ty));
+    }
+}
+
+#[test]
+fn invalid_responses() {
+    assert!(serde_json::from_str::<InteractionResponse>(r#"{"type": 3}"#).is_err());
+    assert!(serde_json::from_str::<InteractionResponse>(r#"{"type": 4}"#).is_err());
+
+    let conflict =
+        r#"{"type": 4, "data": {"allowed_mentions": {"parse": ["users"], "users": ["1"]}}}"#;
+    assert!(serde_json::from_str::<InteractionResponse>(conflict).is_err());
+}
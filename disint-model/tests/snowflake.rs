@@ -0,0 +1,72 @@
+use chrono::TimeZone;
+use disint_model::Snowflake;
+
+#[test]
+fn parse() {
+    let id: Snowflake = "175928847299117063".parse().unwrap();
+    assert_eq!(id, 175928847299117063u64);
+    assert_eq!(id.to_string(), "175928847299117063");
+    assert!("".parse::<Snowflake>().is_err());
+    assert!("-1".parse::<Snowflake>().is_err());
+    assert!("12a".parse::<Snowflake>().is_err());
+}
+
+#[test]
+fn deserialize() {
+    let from_str: Snowflake = serde_json::from_str(r#""175928847299117063""#).unwrap();
+    let from_int: Snowflake = serde_json::from_str("175928847299117063").unwrap();
+    assert_eq!(from_str, from_int);
+    assert_eq!(
+        serde_json::to_string(&from_int).unwrap(),
+        r#""175928847299117063""#
+    );
+
+    assert!(serde_json::from_str::<Snowflake>("-1").is_err());
+    assert!(serde_json::from_str::<Snowflake>(r#""abc""#).is_err());
+    assert!(serde_json::from_str::<Snowflake>("1.5").is_err());
+}
+
+#[test]
+fn components() {
+    let id = Snowflake::new(175928847299117063);
+    assert_eq!(id.timestamp_millis(), 1_462_015_105_796);
+    assert_eq!(
+        id.timestamp(),
+        chrono::Utc.timestamp_millis_opt(1_462_015_105_796).unwrap()
+    );
+    assert_eq!(id.worker_id(), 1);
+    assert_eq!(id.process_id(), 0);
+    assert_eq!(id.increment(), 7);
+}
+
+#[test]
+fn from_timestamp() {
+    let time = chrono::Utc.timestamp_millis_opt(1_462_015_105_796).unwrap();
+    let id = Snowflake::from_timestamp(time);
+    assert_eq!(id.timestamp(), time);
+    assert_eq!(id.get() & ((1 << 22) - 1), 0);
+    assert!(id <= Snowflake::new(175928847299117063));
+}
+
+#[test]
+fn from_timestamp_clamps() {
+    let before_epoch = chrono::Utc.with_ymd_and_hms(1960, 1, 1, 0, 0, 0).unwrap();
+    assert_eq!(Snowflake::from_timestamp(before_epoch), Snowflake::new(0));
+
+    let far_future = chrono::Utc.with_ymd_and_hms(3000, 1, 1, 0, 0, 0).unwrap();
+    let id = Snowflake::from_timestamp(far_future);
+    assert_eq!(id.get() >> 22, u64::MAX >> 22);
+    assert!(id.timestamp() < far_future);
+    assert!(id.timestamp() > chrono::Utc.with_ymd_and_hms(2100, 1, 1, 0, 0, 0).unwrap());
+}
+
+#[test]
+fn ordering() {
+    let earlier = Snowflake::new(175928847299117063);
+    let later = Snowflake::new(175928847299117064);
+    assert!(earlier < later);
+
+    let mut ids = vec![later, earlier];
+    ids.sort();
+    assert_eq!(ids, [earlier, later]);
+}
@@ -0,0 +1,241 @@
+use disint_model::command::{
+    ApplicationCommand, ApplicationCommandBuilder, ApplicationCommandOptionType as OptionType,
+    ApplicationCommandType, Violation,
+};
+use disint_model::interaction::ApplicationCommandInteractionData;
+use disint_model::{OptionValue, Snowflake};
+use serde_json::json;
+
+fn command() -> ApplicationCommand {
+    ApplicationCommandBuilder::new("tag", "Manage tags")
+        .subcommand("show", "Show a tag", |builder| {
+            builder
+                .option("name", "Tag name", |builder| {
+                    builder
+                        .required(true)
+                        .ty(OptionType::String)
+                        .min_length(2)
+                        .max_length(8)
+                        .autocomplete(true)
+                })
+                .option("count", "Repeat count", |builder| {
+                    builder
+                        .required(false)
+                        .ty(OptionType::Integer)
+                        .min_value(1)
+                        .max_value(10)
+                })
+                .option("format", "Output format", |builder| {
+                    builder
+                        .required(false)
+                        .ty(OptionType::String)
+                        .choice("Plain", "plain")
+                        .choice("Code block", "code")
+                })
+        })
+        .subcommand("list", "List tags", |builder| builder.without_options())
+        .finish()
+}
+
+fn data(options: serde_json::Value) -> ApplicationCommandInteractionData {
+    serde_json::from_value(json!({
+        "id": "771825006014889984",
+        "name": "tag",
+        "type": 1,
+        "options": options,
+    }))
+    .unwrap()
+}
+
+fn show(options: serde_json::Value) -> ApplicationCommandInteractionData {
+    data(json!([{ "name": "show", "type": 1, "options": options }]))
+}
+
+fn path(path: &[&str]) -> Vec<String> {
+    path.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn valid() {
+    let violations = command().validate(&show(json!([
+        { "name": "name", "type": 3, "value": "rust" },
+        { "name": "count", "type": 4, "value": 10 },
+        { "name": "format", "type": 3, "value": "code" }
+    ])));
+    assert_eq!(violations, []);
+
+    let violations = command().validate(&data(json!([{ "name": "list", "type": 1 }])));
+    assert_eq!(violations, []);
+}
+
+#[test]
+fn command_mismatch() {
+    let data: ApplicationCommandInteractionData = serde_json::from_value(json!({
+        "id": "771825006014889984",
+        "name": "tags",
+        "type": 2,
+    }))
+    .unwrap();
+    let mut command: ApplicationCommand = serde_json::from_value(json!({
+        "id": "771825006014889985",
+        "name": "tag",
+        "description": "Manage tags",
+        "type": 1,
+    }))
+    .unwrap();
+
+    assert_eq!(
+        command.validate(&data),
+        [
+            Violation::CommandId {
+                expected: Snowflake::new(771825006014889985),
+                found: Snowflake::new(771825006014889984),
+            },
+            Violation::CommandName {
+                expected: "tag".to_owned(),
+                found: "tags".to_owned(),
+            },
+            Violation::CommandType {
+                expected: ApplicationCommandType::ChatInput,
+                found: ApplicationCommandType::User,
+            },
+        ]
+    );
+
+    command = serde_json::from_value(json!({
+        "name": "tags",
+        "description": "Manage tags",
+        "type": 2,
+    }))
+    .unwrap();
+    assert_eq!(command.validate(&data), []);
+}
+
+#[test]
+fn unknown_option() {
+    let violations = command().validate(&show(json!([
+        { "name": "name", "type": 3, "value": "rust" },
+        { "name": "color", "type": 3, "value": "red" }
+    ])));
+    assert_eq!(
+        violations,
+        [Violation::UnknownOption {
+            path: path(&["show", "color"])
+        }]
+    );
+}
+
+#[test]
+fn duplicate_option() {
+    let violations = command().validate(&show(json!([
+        { "name": "name", "type": 3, "value": "rust" },
+        { "name": "name", "type": 3, "value": "go" }
+    ])));
+    assert_eq!(
+        violations,
+        [Violation::DuplicateOption {
+            path: path(&["show", "name"])
+        }]
+    );
+}
+
+#[test]
+fn missing_option() {
+    let violations = command().validate(&show(json!([
+        { "name": "count", "type": 4, "value": 1 }
+    ])));
+    assert_eq!(
+        violations,
+        [Violation::MissingOption {
+            path: path(&["show", "name"])
+        }]
+    );
+}
+
+#[test]
+fn missing_subcommand() {
+    let violations = command().validate(&data(json!([])));
+    assert_eq!(
+        violations,
+        [Violation::MissingSubcommand { path: path(&[]) }]
+    );
+}
+
+#[test]
+fn wrong_type() {
+    let violations = command().validate(&show(json!([
+        { "name": "name", "type": 3, "value": "rust" },
+        { "name": "count", "type": 10, "value": 1.5 }
+    ])));
+    assert_eq!(
+        violations,
+        [Violation::WrongType {
+            path: path(&["show", "count"]),
+            expected: OptionType::Integer,
+            found: OptionType::Number,
+        }]
+    );
+}
+
+#[test]
+fn invalid_choice() {
+    let violations = command().validate(&show(json!([
+        { "name": "name", "type": 3, "value": "rust" },
+        { "name": "format", "type": 3, "value": "html" }
+    ])));
+    assert_eq!(
+        violations,
+        [Violation::InvalidChoice {
+            path: path(&["show", "format"]),
+            value: OptionValue::from("html"),
+        }]
+    );
+}
+
+#[test]
+fn out_of_range() {
+    let violations = command().validate(&show(json!([
+        { "name": "name", "type": 3, "value": "rust" },
+        { "name": "count", "type": 4, "value": 11 }
+    ])));
+    assert_eq!(
+        violations,
+        [Violation::OutOfRange {
+            path: path(&["show", "count"]),
+            value: OptionValue::from(11i64),
+            min: Some(OptionValue::from(1)),
+            max: Some(OptionValue::from(10)),
+        }]
+    );
+}
+
+#[test]
+fn invalid_length() {
+    let violations = command().validate(&show(json!([
+        { "name": "name", "type": 3, "value": "러스트러스트러스트" }
+    ])));
+    assert_eq!(
+        violations,
+        [Violation::InvalidLength {
+            path: path(&["show", "name"]),
+            length: 9,
+            min: Some(2),
+            max: Some(8),
+        }]
+    );
+}
+
+#[test]
+fn autocomplete_skips_required_options() {
+    let violations = command().validate(&show(json!([
+        { "name": "count", "type": 4, "value": 1 },
+        { "name": "format", "type": 3, "value": "h", "focused": true }
+    ])));
+    assert_eq!(violations, []);
+
+    // The focused value is partial input, so its constraints aren't checked.
+    let violations = command().validate(&show(json!([
+        { "name": "name", "type": 3, "value": "r", "focused": true }
+    ])));
+    assert_eq!(violations, []);
+}